@@ -0,0 +1,52 @@
+use common::Solution;
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::fs;
+use std::hint::black_box;
+
+fn bench_day<S: Solution>(c: &mut Criterion) {
+    let path = format!("{}/day{}/input.txt", env!("CARGO_MANIFEST_DIR"), S::DAY);
+    let input = fs::read_to_string(&path).unwrap_or_else(|err| panic!("{}: {}", path, err));
+    let parsed = S::parse(&input).unwrap();
+
+    let mut group = c.benchmark_group(format!("day{}", S::DAY));
+    group.sample_size(10);
+    group.bench_function("parse", |b| b.iter(|| S::parse(black_box(&input))));
+    if S::RUNNABLE[0] {
+        group.bench_function("part1", |b| b.iter(|| S::part1(black_box(&parsed))));
+    }
+    if S::RUNNABLE[1] {
+        group.bench_function("part2", |b| b.iter(|| S::part2(black_box(&parsed))));
+    }
+    group.finish();
+}
+
+fn days(c: &mut Criterion) {
+    bench_day::<day1::Day1>(c);
+    bench_day::<day2::Day2>(c);
+    bench_day::<day3::Day3>(c);
+    bench_day::<day4::Day4>(c);
+    bench_day::<day5::Day5>(c);
+    bench_day::<day6::Day6>(c);
+    bench_day::<day7::Day7>(c);
+    bench_day::<day8::Day8>(c);
+    bench_day::<day9::Day9>(c);
+    bench_day::<day10::Day10>(c);
+    bench_day::<day11::Day11>(c);
+    bench_day::<day12::Day12>(c);
+    bench_day::<day13::Day13>(c);
+    bench_day::<day14::Day14>(c);
+    bench_day::<day15::Day15>(c);
+    bench_day::<day16::Day16>(c);
+    bench_day::<day17::Day17>(c);
+    bench_day::<day18::Day18>(c);
+    bench_day::<day19::Day19>(c);
+    bench_day::<day20::Day20>(c);
+    bench_day::<day21::Day21>(c);
+    bench_day::<day22::Day22>(c);
+    bench_day::<day23::Day23>(c);
+    bench_day::<day24::Day24>(c);
+    bench_day::<day25::Day25>(c);
+}
+
+criterion_group!(benches, days);
+criterion_main!(benches);
@@ -0,0 +1,77 @@
+use std::fmt;
+
+/// A puzzle answer in a form the runner can print and compare, whatever
+/// type the day itself returns.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Int(i128),
+    /// An unsigned answer too large for [`Answer::Int`].
+    Big(u128),
+    Pair(usize, usize),
+    /// The part has no answer, e.g. day 25 part 2.
+    None,
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{}", n),
+            Answer::Big(n) => write!(f, "{}", n),
+            Answer::Pair(a, b) => write!(f, "({}, {})", a, b),
+            Answer::None => write!(f, "-"),
+        }
+    }
+}
+
+impl Answer {
+    /// The answer as a JSON value: a number, a `[a, b]` array or `null`.
+    pub fn to_json(&self) -> String {
+        match self {
+            Answer::Int(n) => n.to_string(),
+            Answer::Big(n) => n.to_string(),
+            Answer::Pair(a, b) => format!("[{},{}]", a, b),
+            Answer::None => "null".to_string(),
+        }
+    }
+}
+
+macro_rules! from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Answer {
+                    Answer::Int(n as i128)
+                }
+            }
+        )*
+    };
+}
+
+from_int!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, i128);
+
+impl From<u128> for Answer {
+    fn from(n: u128) -> Answer {
+        match i128::try_from(n) {
+            Ok(n) => Answer::Int(n),
+            Err(_) => Answer::Big(n),
+        }
+    }
+}
+
+impl From<(usize, usize)> for Answer {
+    fn from((a, b): (usize, usize)) -> Answer {
+        Answer::Pair(a, b)
+    }
+}
+
+impl From<()> for Answer {
+    fn from(_: ()) -> Answer {
+        Answer::None
+    }
+}
+
+impl<T: Into<Answer>> From<Option<T>> for Answer {
+    fn from(answer: Option<T>) -> Answer {
+        answer.map_or(Answer::None, Into::into)
+    }
+}
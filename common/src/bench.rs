@@ -0,0 +1,61 @@
+use crate::parse::ParseError;
+use crate::solution::{self, Solution};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// Wall times over several runs of the same step.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timings {
+    pub min: Duration,
+    pub mean: Duration,
+    pub max: Duration,
+}
+
+impl Timings {
+    /// Times `runs` calls of `f`, at least one.
+    pub fn measure<T>(runs: u32, mut f: impl FnMut() -> T) -> Timings {
+        let runs = runs.max(1);
+        let mut total = Duration::ZERO;
+        let mut min = Duration::MAX;
+        let mut max = Duration::ZERO;
+
+        for _ in 0..runs {
+            let start = Instant::now();
+            black_box(f());
+            let elapsed = start.elapsed();
+
+            total += elapsed;
+            min = min.min(elapsed);
+            max = max.max(elapsed);
+        }
+
+        Timings {
+            min,
+            mean: total / runs,
+            max,
+        }
+    }
+}
+
+/// Timings of parsing and of both parts, `None` for parts that weren't run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bench {
+    pub parse: Timings,
+    pub parts: [Option<Timings>; 2],
+}
+
+/// Like [`solution::solve`], but runs every step `runs` times and times it
+/// instead of returning the answers.
+pub fn bench<S: Solution>(input: &str, part: Option<u8>, runs: u32) -> Result<Bench, ParseError> {
+    let parsed = solution::parse::<S>(input)?;
+
+    let parse = Timings::measure(runs, || S::parse(black_box(input)));
+    let [run1, run2] = solution::parts_to_run::<S>(part);
+    let part1 = run1.then(|| Timings::measure(runs, || S::part1(black_box(&parsed))));
+    let part2 = run2.then(|| Timings::measure(runs, || S::part2(black_box(&parsed))));
+
+    Ok(Bench {
+        parse,
+        parts: [part1, part2],
+    })
+}
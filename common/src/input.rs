@@ -0,0 +1,113 @@
+use crate::parse::ParseError;
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::PathBuf;
+use std::{error, process, str};
+
+/// Where the puzzle input is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Stdin,
+    File(PathBuf),
+}
+
+impl Source {
+    /// `-` selects stdin, anything else is treated as a file path.
+    pub fn from_arg(arg: &str) -> Source {
+        match arg {
+            "-" => Source::Stdin,
+            path => Source::File(PathBuf::from(path)),
+        }
+    }
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Source::Stdin => write!(f, "<stdin>"),
+            Source::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    Io { from: Source, err: io::Error },
+    Utf8 { from: Source, err: str::Utf8Error },
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InputError::Io { from, err } if err.kind() == io::ErrorKind::NotFound => {
+                write!(f, "input file {} not found", from)
+            }
+            InputError::Io { from, err } => write!(f, "failed to read {}: {}", from, err),
+            InputError::Utf8 { from, err } => write!(f, "{} is not valid UTF-8: {}", from, err),
+        }
+    }
+}
+
+impl error::Error for InputError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            InputError::Io { err, .. } => Some(err),
+            InputError::Utf8 { err, .. } => Some(err),
+        }
+    }
+}
+
+pub fn read(from: &Source) -> Result<String, InputError> {
+    let bytes = match from {
+        Source::Stdin => {
+            let mut buf = Vec::new();
+            io::stdin().read_to_end(&mut buf).map(|_| buf)
+        }
+        Source::File(path) => fs::read(path),
+    }
+    .map_err(|err| InputError::Io {
+        from: from.clone(),
+        err,
+    })?;
+
+    String::from_utf8(bytes).map_err(|err| InputError::Utf8 {
+        from: from.clone(),
+        err: err.utf8_error(),
+    })
+}
+
+/// What `builtin` makes of `name` if it's one of the built in ones, otherwise
+/// the file `name` read with `parse`. Errors say which file they're from.
+pub fn builtin_or_file<T>(
+    name: &str,
+    builtin: impl FnOnce(&str) -> Option<T>,
+    parse: impl FnOnce(&str) -> Result<T, ParseError>,
+) -> Result<T, String> {
+    match builtin(name) {
+        Some(value) => Ok(value),
+        None => file_with(name, parse),
+    }
+}
+
+/// The file `path` read with `parse`. Errors say which file they're from.
+pub fn file_with<T>(
+    path: &str,
+    parse: impl FnOnce(&str) -> Result<T, ParseError>,
+) -> Result<T, String> {
+    let text =
+        fs::read_to_string(path).map_err(|err| format!("failed to read {}: {}", path, err))?;
+    parse(&text).map_err(|err| format!("{}: {}", path, err))
+}
+
+/// Like [`read`], but meant to be called from `main`: on failure the error
+/// is printed and the process exits.
+pub fn read_or_exit(from: &Source) -> String {
+    match read(from) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use std::fmt;
+use std::{error, str};
+
+/// Bad puzzle input. `line` and `column` are 1-based, `column` counts
+/// characters rather than bytes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Filled in by the runner, parsers don't know which day they belong to.
+    pub day: Option<u8>,
+    pub line: usize,
+    pub column: usize,
+    /// The offending part of the input, empty if something was missing.
+    pub text: String,
+    /// What was expected instead.
+    pub reason: String,
+}
+
+impl ParseError {
+    /// Builds an error for `text`, which has to be a slice of `input`: the
+    /// line and column are worked out from where it starts.
+    pub fn at(input: &str, text: &str, reason: impl Into<String>) -> ParseError {
+        let start = input.as_ptr() as usize;
+        let offset = (text.as_ptr() as usize).wrapping_sub(start);
+        debug_assert!(offset <= input.len(), "text is not part of the input");
+        let offset = offset.min(input.len());
+
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+
+        ParseError {
+            day: None,
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            text: text.to_string(),
+            reason: reason.into(),
+        }
+    }
+
+    /// Moves an error found while parsing `inner`, a slice of `outer`, to
+    /// where it is in `outer`.
+    pub fn within(self, outer: &str, inner: &str) -> ParseError {
+        let start = ParseError::at(outer, inner, "");
+        let column = match self.line {
+            1 => start.column + self.column - 1,
+            _ => self.column,
+        };
+
+        ParseError {
+            line: start.line + self.line - 1,
+            column,
+            ..self
+        }
+    }
+
+    pub fn with_day(self, day: u8) -> ParseError {
+        ParseError {
+            day: Some(day),
+            ..self
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "day {}, ", day)?;
+        }
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.reason
+        )?;
+
+        match self.text.as_str() {
+            "" => write!(f, ", found nothing"),
+            text => write!(f, ", found {:?}", text),
+        }
+    }
+}
+
+impl error::Error for ParseError {}
+
+/// Parses `text`, a slice of `input`, as a number.
+pub fn number<T: str::FromStr>(input: &str, text: &str) -> Result<T, ParseError> {
+    text.trim()
+        .parse()
+        .map_err(|_| ParseError::at(input, text, "expected a number"))
+}
+
+/// `str::split_once` on `text`, a slice of `input`, that reports a missing
+/// separator.
+pub fn split_once<'a>(
+    input: &str,
+    text: &'a str,
+    sep: &str,
+) -> Result<(&'a str, &'a str), ParseError> {
+    text.split_once(sep)
+        .ok_or_else(|| ParseError::at(input, text, format!("expected {:?}", sep)))
+}
@@ -0,0 +1,56 @@
+use crate::bench::Timings;
+use crate::solution::Timed;
+use std::time::Duration;
+
+/// How results are printed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Format {
+    #[default]
+    Text,
+    /// One JSON object per line.
+    Json,
+}
+
+impl Format {
+    pub fn from_arg(arg: &str) -> Option<Format> {
+        match arg {
+            "text" => Some(Format::Text),
+            "json" => Some(Format::Json),
+            _ => None,
+        }
+    }
+}
+
+fn ms(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+/// `{"day":N,"part":P,"answer":...,"elapsed_ms":...}`
+pub fn answer_json(day: u8, part: u8, timed: &Timed) -> String {
+    format!(
+        r#"{{"day":{},"part":{},"answer":{},"elapsed_ms":{:.3}}}"#,
+        day,
+        part,
+        timed.answer.to_json(),
+        ms(timed.elapsed)
+    )
+}
+
+/// `{"day":N,"part":P,"skipped":true}`, for a part that isn't run.
+pub fn skipped_json(day: u8, part: u8) -> String {
+    format!(r#"{{"day":{},"part":{},"skipped":true}}"#, day, part)
+}
+
+/// `{"day":N,"step":"parse","runs":R,"min_ms":...,"mean_ms":...,"max_ms":...}`,
+/// where the step is `parse`, `part1` or `part2`.
+pub fn timings_json(day: u8, step: &str, runs: u32, timings: &Timings) -> String {
+    format!(
+        r#"{{"day":{},"step":"{}","runs":{},"min_ms":{:.3},"mean_ms":{:.3},"max_ms":{:.3}}}"#,
+        day,
+        step,
+        runs,
+        ms(timings.min),
+        ms(timings.mean),
+        ms(timings.max)
+    )
+}
@@ -0,0 +1,258 @@
+use crate::answer::Answer;
+use crate::input::{self, Source};
+use crate::parse::ParseError;
+use crate::report::{self, Format};
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+use std::{env, process};
+
+/// An answer along with how long its part took to run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Timed {
+    pub answer: Answer,
+    pub elapsed: Duration,
+}
+
+impl Timed {
+    fn run<T: Into<Answer>>(part: impl FnOnce() -> T) -> Timed {
+        let start = Instant::now();
+        let answer = part().into();
+
+        Timed {
+            answer,
+            elapsed: start.elapsed(),
+        }
+    }
+}
+
+/// The answers to both parts, `None` for parts that weren't run.
+pub type Answers = [Option<Timed>; 2];
+
+pub trait Solution {
+    const DAY: u8;
+
+    /// What both parts work on, so the input is only parsed once.
+    type Input;
+    type Part1: Into<Answer>;
+    type Part2: Into<Answer>;
+
+    /// Whether each part can run at all. Parts that can't are skipped, not
+    /// run and left to never finish.
+    const RUNNABLE: [bool; 2] = [true, true];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Self::Part1;
+    fn part2(input: &Self::Input) -> Self::Part2;
+}
+
+/// Which parts of `S` to run when asked for `part`, or both if `None`. Parts
+/// that can't run are left out, see [`Solution::RUNNABLE`].
+pub fn parts_to_run<S: Solution>(part: Option<u8>) -> [bool; 2] {
+    [
+        part != Some(2) && S::RUNNABLE[0],
+        part != Some(1) && S::RUNNABLE[1],
+    ]
+}
+
+/// Parses `input` and runs the requested part, or both if `part` is `None`.
+pub fn solve<S: Solution>(input: &str, part: Option<u8>) -> Result<Answers, ParseError> {
+    let input = parse::<S>(input)?;
+
+    let [run1, run2] = parts_to_run::<S>(part);
+    let part1 = run1.then(|| Timed::run(|| S::part1(&input)));
+    let part2 = run2.then(|| Timed::run(|| S::part2(&input)));
+
+    Ok([part1, part2])
+}
+
+/// `S::parse` with the day filled in on errors.
+pub fn parse<S: Solution>(input: &str) -> Result<S::Input, ParseError> {
+    S::parse(input).map_err(|err| err.with_day(S::DAY))
+}
+
+fn print_part(day: u8, part: u8, timed: Option<Timed>, format: Format) {
+    match (timed, format) {
+        (Some(timed), Format::Text) => println!("Part{}: {}", part, timed.answer),
+        (Some(timed), Format::Json) => println!("{}", report::answer_json(day, part, &timed)),
+        (None, Format::Text) => println!("Part{}: skipped", part),
+        (None, Format::Json) => println!("{}", report::skipped_json(day, part)),
+    }
+}
+
+fn usage_error(day: u8, msg: &str) -> ! {
+    eprintln!(
+        "error: {}\nusage: day{} [<input file>|-] [--format text|json]",
+        msg, day
+    );
+    process::exit(2);
+}
+
+/// Shared `main` of the day binaries: reads the input named on the command
+/// line, or `default_input`, and prints both answers.
+pub fn main<S: Solution>(default_input: &str) {
+    let mut from = None;
+    let mut format = Format::Text;
+
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--format" => {
+                let val = args.next().unwrap_or_default();
+                format = Format::from_arg(&val)
+                    .unwrap_or_else(|| usage_error(S::DAY, &format!("invalid format: {}", val)));
+            }
+            _ if from.is_none() => from = Some(Source::from_arg(&arg)),
+            _ => usage_error(S::DAY, &format!("unexpected argument: {}", arg)),
+        }
+    }
+    let from = from.unwrap_or_else(|| Source::File(PathBuf::from(default_input)));
+
+    let input = match parse::<S>(&input::read_or_exit(&from)) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            process::exit(1);
+        }
+    };
+
+    let [run1, run2] = parts_to_run::<S>(None);
+    let part1 = run1.then(|| Timed::run(|| S::part1(&input)));
+    let part2 = run2.then(|| Timed::run(|| S::part2(&input)));
+    print_part(S::DAY, 1, part1, format);
+    print_part(S::DAY, 2, part2, format);
+}
+
+/// The modes a day binary has besides printing the answers.
+pub struct Modes<'a> {
+    /// The options that switch to one of the modes.
+    pub flags: &'a [&'a str],
+    /// The options followed by a value, mode flags included.
+    pub with_value: &'a [&'a str],
+    pub usage: &'a str,
+}
+
+/// The options given to one of the [`Modes`], in order, with their values.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Options {
+    given: Vec<(String, Option<String>)>,
+}
+
+impl Options {
+    pub fn has(&self, name: &str) -> bool {
+        self.given.iter().any(|(opt, _)| opt == name)
+    }
+
+    /// The value of the last `name` option.
+    pub fn value(&self, name: &str) -> Option<&str> {
+        self.given
+            .iter()
+            .rev()
+            .find(|(opt, _)| opt == name)
+            .and_then(|(_, value)| value.as_deref())
+    }
+
+    /// The values of every `name` option.
+    pub fn values<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a str> {
+        self.given
+            .iter()
+            .filter(move |(opt, _)| opt == name)
+            .filter_map(|(_, value)| value.as_deref())
+    }
+}
+
+// The input named on the command line, if any, and the options.
+fn read_options(args: &[String], modes: &Modes) -> Result<(Option<Source>, Options), String> {
+    let mut from = None;
+    let mut options = Options::default();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let name = arg.as_str();
+        if modes.with_value.contains(&name) {
+            let value = args
+                .next()
+                .ok_or_else(|| format!("{} needs a value", arg))?;
+            options.given.push((arg.clone(), Some(value.clone())));
+        } else if modes.flags.contains(&name) {
+            options.given.push((arg.clone(), None));
+        } else if from.is_none() && !arg.starts_with("--") {
+            from = Some(Source::from_arg(arg));
+        } else {
+            return Err(format!("unexpected argument: {}", arg));
+        }
+    }
+
+    Ok((from, options))
+}
+
+/// [`main`] for day binaries with other [`Modes`]. If one of `modes.flags`
+/// is on the command line, `parse_args` turns the options into the mode's
+/// arguments and `run` runs it on the input instead. A bad command line
+/// prints `modes.usage` and exits with 2, a failed run exits with 1.
+pub fn main_with_modes<S: Solution, A>(
+    default_input: &str,
+    modes: &Modes,
+    parse_args: impl FnOnce(&Options) -> Result<A, String>,
+    run: impl FnOnce(A, &str) -> Result<(), String>,
+) {
+    let args: Vec<String> = env::args().skip(1).collect();
+    if !args.iter().any(|arg| modes.flags.contains(&arg.as_str())) {
+        main::<S>(default_input);
+        return;
+    }
+
+    let parsed =
+        read_options(&args, modes).and_then(|(from, options)| Ok((from, parse_args(&options)?)));
+    let (from, mode_args) = match parsed {
+        Ok(parsed) => parsed,
+        Err(err) => {
+            eprintln!("error: {}\n{}", err, modes.usage);
+            process::exit(2);
+        }
+    };
+
+    let from = from.unwrap_or_else(|| Source::File(PathBuf::from(default_input)));
+    if let Err(err) = run(mode_args, &input::read_or_exit(&from)) {
+        eprintln!("error: {}", err);
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MODES: Modes = Modes {
+        flags: &["--table", "--below"],
+        with_value: &["--below", "--words"],
+        usage: "",
+    };
+
+    fn read(args: &[&str]) -> Result<(Option<Source>, Options), String> {
+        let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+        read_options(&args, &MODES)
+    }
+
+    #[test]
+    fn reading_options() {
+        let (from, options) = read(&["--below", "5", "-", "--words", "a", "--words", "b"]).unwrap();
+        assert_eq!(from, Some(Source::Stdin));
+        assert_eq!(options.value("--below"), Some("5"));
+        assert_eq!(options.values("--words").collect::<Vec<_>>(), ["a", "b"]);
+        assert!(!options.has("--table"));
+
+        let (from, options) = read(&["--table"]).unwrap();
+        assert_eq!((from, options.has("--table")), (None, true));
+        assert_eq!(options.value("--table"), None);
+
+        assert_eq!(read(&["--below"]), Err("--below needs a value".to_string()));
+        assert_eq!(
+            read(&["--table", "in.txt", "x"]),
+            Err("unexpected argument: x".to_string())
+        );
+        assert_eq!(
+            read(&["--table", "--format", "json"]),
+            Err("unexpected argument: --format".to_string())
+        );
+    }
+}
@@ -0,0 +1,195 @@
+use aho_corasick::AhoCorasick;
+use common::input;
+use parsing::ParseError;
+use std::cmp::Reverse;
+use std::ops::Range;
+
+pub const ENGLISH: &[(&str, u32)] = &[
+    ("zero", 0),
+    ("one", 1),
+    ("two", 2),
+    ("three", 3),
+    ("four", 4),
+    ("five", 5),
+    ("six", 6),
+    ("seven", 7),
+    ("eight", 8),
+    ("nine", 9),
+];
+
+pub const GERMAN: &[(&str, u32)] = &[
+    ("null", 0),
+    ("eins", 1),
+    ("zwei", 2),
+    ("drei", 3),
+    ("vier", 4),
+    ("fünf", 5),
+    ("sechs", 6),
+    ("sieben", 7),
+    ("acht", 8),
+    ("neun", 9),
+];
+
+pub const FRENCH: &[(&str, u32)] = &[
+    ("zéro", 0),
+    ("un", 1),
+    ("deux", 2),
+    ("trois", 3),
+    ("quatre", 4),
+    ("cinq", 5),
+    ("six", 6),
+    ("sept", 7),
+    ("huit", 8),
+    ("neuf", 9),
+];
+
+/// A digit found in a line. `span` is the byte range it was read from, a
+/// word worth more than 9 gives one digit per decimal digit, all with the
+/// word's span.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Digit {
+    pub value: u32,
+    pub span: Range<usize>,
+    pub word: bool,
+}
+
+/// Finds the digits in a line, both plain ones and words from a table, in a
+/// single pass. Matches may overlap, "twone" is a 2 and a 1.
+pub struct DigitDecoder {
+    matcher: AhoCorasick,
+    // The digits of each pattern, the first ten are '0' to '9'.
+    values: Vec<Vec<u32>>,
+}
+
+impl DigitDecoder {
+    pub fn new<'a>(words: impl IntoIterator<Item = (&'a str, u32)>) -> DigitDecoder {
+        let mut patterns: Vec<String> = (0..10).map(|d| d.to_string()).collect();
+        let mut values: Vec<Vec<u32>> = (0..10).map(|d| vec![d]).collect();
+        for (word, value) in words {
+            patterns.push(word.to_string());
+            values.push(
+                value
+                    .to_string()
+                    .chars()
+                    .filter_map(|c| c.to_digit(10))
+                    .collect(),
+            );
+        }
+
+        let matcher = AhoCorasick::new(&patterns).expect("digit patterns are small");
+        DigitDecoder { matcher, values }
+    }
+
+    /// Only reads plain digits.
+    pub fn digits_only() -> DigitDecoder {
+        DigitDecoder::new([])
+    }
+
+    /// One of the built in tables by name: english, german or french.
+    pub fn language(name: &str) -> Option<DigitDecoder> {
+        let table = match name {
+            "english" => ENGLISH,
+            "german" => GERMAN,
+            "french" => FRENCH,
+            _ => return None,
+        };
+
+        Some(DigitDecoder::new(table.iter().copied()))
+    }
+
+    /// A table with a `word = value` line per word, see [`parsing::settings`].
+    pub fn parse_table(text: &str) -> Result<DigitDecoder, ParseError> {
+        let words = parsing::settings(text)?
+            .into_iter()
+            .map(|(word, value)| Ok((word, parsing::number(text, value)?)))
+            .collect::<Result<Vec<_>, ParseError>>()?;
+
+        Ok(DigitDecoder::new(words))
+    }
+
+    /// A built in table if `name` is one, otherwise a table file, see
+    /// [`DigitDecoder::parse_table`].
+    pub fn load(name: &str) -> Result<DigitDecoder, String> {
+        input::builtin_or_file(name, DigitDecoder::language, DigitDecoder::parse_table)
+    }
+
+    /// Every digit in `line`, by where it starts. Of two words starting at
+    /// the same place only the longer one counts, "twentyone" is 21 and not
+    /// also 20.
+    pub fn decode(&self, line: &str) -> Vec<Digit> {
+        let mut matches: Vec<_> = self.matcher.find_overlapping_iter(line).collect();
+        matches.sort_by_key(|m| (m.start(), Reverse(m.end())));
+        matches.dedup_by_key(|m| m.start());
+
+        matches
+            .into_iter()
+            .flat_map(|m| {
+                let pattern = m.pattern().as_usize();
+                self.values[pattern].iter().map(move |&value| Digit {
+                    value,
+                    span: m.range(),
+                    word: pattern >= 10,
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values(decoder: &DigitDecoder, line: &str) -> Vec<u32> {
+        decoder.decode(line).iter().map(|d| d.value).collect()
+    }
+
+    #[test]
+    fn overlapping_words() {
+        let english = DigitDecoder::language("english").unwrap();
+        assert_eq!(values(&english, "twone"), [2, 1]);
+        assert_eq!(values(&english, "eightwothree"), [8, 2, 3]);
+        assert_eq!(values(&english, "zerone7"), [0, 1, 7]);
+
+        let digits = english.decode("xtwone3");
+        assert_eq!(digits[0].span, 1..4);
+        assert_eq!(digits[1].span, 3..6);
+        assert_eq!(
+            digits[2],
+            Digit {
+                value: 3,
+                span: 6..7,
+                word: false
+            }
+        );
+        assert!(digits[0].word && digits[1].word);
+    }
+
+    #[test]
+    fn other_languages() {
+        let german = DigitDecoder::language("german").unwrap();
+        assert_eq!(values(&german, "fünfundzwei3null"), [5, 2, 3, 0]);
+        assert_eq!(german.decode("fünf")[0].span, 0..5);
+
+        let french = DigitDecoder::language("french").unwrap();
+        assert_eq!(values(&french, "deuxcinq9"), [2, 5, 9]);
+        assert_eq!(values(&DigitDecoder::digits_only(), "one2three4"), [2, 4]);
+    }
+
+    #[test]
+    fn tables() {
+        let decoder =
+            DigitDecoder::parse_table("# teens\nten = 10\n\nseventeen = 17\nseven=7\n").unwrap();
+        assert_eq!(values(&decoder, "xseventeen"), [1, 7]);
+        assert_eq!(values(&decoder, "ten5"), [1, 0, 5]);
+
+        let decoder = DigitDecoder::parse_table("twenty = 20\ntwentyone = 21\n").unwrap();
+        assert_eq!(values(&decoder, "twentyone"), [2, 1]);
+        assert_eq!(crate::calibrate(&decoder, "twentyone").unwrap().value(), 21);
+        assert_eq!(values(&decoder, "twentytwo"), [2, 0]);
+
+        let err = DigitDecoder::parse_table("one = 1\ntwo 2\n").err().unwrap();
+        assert_eq!((err.line, err.reason.as_str()), (2, "expected \"=\""));
+        let err = DigitDecoder::parse_table("one = x\n").err().unwrap();
+        assert_eq!((err.column, err.text.as_str()), (7, "x"));
+    }
+}
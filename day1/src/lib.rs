@@ -0,0 +1,127 @@
+pub mod decoder;
+
+pub use decoder::{Digit, DigitDecoder};
+
+use common::Solution;
+use parsing::ParseError;
+
+/// The words the puzzle spells out for part 2, one to nine.
+pub fn puzzle_words() -> DigitDecoder {
+    DigitDecoder::new(
+        decoder::ENGLISH
+            .iter()
+            .copied()
+            .filter(|&(_, value)| value != 0),
+    )
+}
+
+/// The first and last digit of a line, which make up its calibration value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Calibration {
+    pub first: Digit,
+    pub last: Digit,
+}
+
+impl Calibration {
+    pub fn value(&self) -> u32 {
+        10 * self.first.value + self.last.value
+    }
+}
+
+/// `None` if `line` has no digits.
+pub fn calibrate(decoder: &DigitDecoder, line: &str) -> Option<Calibration> {
+    let digits = decoder.decode(line);
+
+    Some(Calibration {
+        first: digits.first()?.clone(),
+        last: digits.last()?.clone(),
+    })
+}
+
+/// Lines without digits count as `default`, or make the sum `None` if
+/// there is none.
+pub fn calibration_sum(
+    lines: &[String],
+    decoder: &DigitDecoder,
+    default: Option<u32>,
+) -> Option<u32> {
+    lines
+        .iter()
+        .map(|line| calibrate(decoder, line).map(|c| c.value()).or(default))
+        .sum()
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+    type Input = Vec<String>;
+    type Part1 = Option<u32>;
+    type Part2 = Option<u32>;
+
+    fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+        Ok(input.lines().map(String::from).collect())
+    }
+
+    fn part1(input: &Vec<String>) -> Option<u32> {
+        calibration_sum(input, &DigitDecoder::digits_only(), None)
+    }
+
+    fn part2(input: &Vec<String>) -> Option<u32> {
+        calibration_sum(input, &puzzle_words(), None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+    const EXAMPLE2: &str = include_str!("../example2.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day1::part1(&Day1::parse(EXAMPLE).unwrap()), Some(142));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day1::part2(&Day1::parse(EXAMPLE2).unwrap()), Some(281));
+    }
+
+    #[test]
+    fn first_and_last_digits() {
+        let calibration = calibrate(&puzzle_words(), "xtwone3four").unwrap();
+        assert_eq!(calibration.value(), 24);
+        assert_eq!(
+            (calibration.first.span, calibration.first.word),
+            (1..4, true)
+        );
+        assert_eq!(
+            (calibration.last.span, calibration.last.word),
+            (7..11, true)
+        );
+
+        let calibration = calibrate(&DigitDecoder::digits_only(), "treb7uchet").unwrap();
+        assert_eq!(calibration.first, calibration.last);
+        assert_eq!(calibration.value(), 77);
+    }
+
+    #[test]
+    fn lines_without_digits() {
+        let lines: Vec<String> = ["1abc2", "nodigits", "a7"].map(String::from).into();
+        assert_eq!(calibrate(&DigitDecoder::digits_only(), "nodigits"), None);
+        assert_eq!(
+            calibration_sum(&lines, &DigitDecoder::digits_only(), None),
+            None
+        );
+        assert_eq!(
+            calibration_sum(&lines, &DigitDecoder::digits_only(), Some(0)),
+            Some(89)
+        );
+        assert_eq!(
+            calibration_sum(&lines, &DigitDecoder::digits_only(), Some(10)),
+            Some(99)
+        );
+    }
+}
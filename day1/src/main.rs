@@ -1,70 +1,81 @@
-use std::str; 
-use regex::Regex;
-use std::collections::HashMap;
-
-fn try_parse_num(line: &str, i: usize) -> Option<u32> {
-
-    let numbers_map: HashMap<&str, u32> = [("one", 1), ("two", 2), ("three", 3), ("four", 4), ("five", 5), ("six", 6), ("seven",7),
-                    ("eight",8), ("nine", 9)].into();
+use common::solution::{self, Modes, Options};
+use day1::{Day1, Digit, DigitDecoder};
+
+const INPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+const USAGE: &str = "\
+usage: day1 [<input file>|-] [--format text|json]
+       day1 --explain [<input file>|-] [--words digits|english|german|french|<file>] [--default <n>]
+
+--explain shows where each line's calibration value comes from, using the
+part 2 words unless --words says otherwise. Lines without digits count as
+--default, if given.";
+const MODES: Modes = Modes {
+    flags: &["--explain"],
+    with_value: &["--words", "--default"],
+    usage: USAGE,
+};
+
+struct ExplainArgs {
+    decoder: DigitDecoder,
+    default: Option<u32>,
+}
 
-    let mut ret = None;
-    for &s in numbers_map.keys() {
-        if i + s.len() <= line.len() {
-            if line[i..i+s.len()] == *s {
-                ret = Some(numbers_map[s]);
-                break;
-            }
-        }
+fn parse_explain_args(options: &Options) -> Result<ExplainArgs, String> {
+    let decoder = match options.value("--words") {
+        None => day1::puzzle_words(),
+        Some("digits") => DigitDecoder::digits_only(),
+        Some(name) => DigitDecoder::load(name)?,
+    };
+    let default = options
+        .value("--default")
+        .map(|val| val.parse().map_err(|_| format!("invalid default: {}", val)))
+        .transpose()?;
+
+    Ok(ExplainArgs { decoder, default })
+}
 
+fn describe(line: &str, digit: &Digit) -> String {
+    let text = &line[digit.span.clone()];
+    match digit.word {
+        true => format!("{} from {:?} at {:?}", digit.value, text, digit.span),
+        false => format!("{} at {:?}", digit.value, digit.span),
     }
-
-    ret
 }
 
-fn part2(input: &str) {
-    let mut sum = 0;
-    for l in input.lines() {
-        let mut digits = Vec::new();
-        for (i, c) in l.chars().enumerate() {
-            if c.is_digit(10) {
-                digits.push(c.to_digit(10).unwrap());
+fn explain(args: ExplainArgs, input: &str) -> Result<(), String> {
+    let mut total = Some(0);
+    for line in input.lines() {
+        let value = match day1::calibrate(&args.decoder, line) {
+            Some(calibration) => {
+                let value = calibration.value();
+                println!(
+                    "{:>3}  {}: first {}, last {}",
+                    value,
+                    line,
+                    describe(line, &calibration.first),
+                    describe(line, &calibration.last)
+                );
+                Some(value)
             }
-            else {
-                if let Some(num) = try_parse_num(l, i) {
-                    digits.push(num);
+            None => {
+                match args.default {
+                    Some(n) => println!("{:>3}  {}: no digits, counted as {}", n, line, n),
+                    None => println!("{:>3}  {}: no digits", "-", line),
                 }
+                args.default
             }
-        }
-
-        let num = format!("{}{}", digits.first().unwrap(), digits.last().unwrap());
-        let num = num.parse::<u32>().unwrap();
-
-        sum += num;
+        };
+        total = total.zip(value).map(|(total, value)| total + value);
     }
 
-    println!("Part2: {}", sum);
-
-}
-
-fn part1(input: &str) {
-    let mut sum = 0;
-    for l in input.lines() {
-        let digits: Vec<char> = l.chars().filter(|x| x.is_digit(10)).collect();
-
-        let num = format!("{}{}", digits.first().unwrap(), digits.last().unwrap());
-        let num = num.parse::<u32>().unwrap();
-
-        sum += num;
+    match total {
+        Some(total) => println!("total: {}", total),
+        None => println!("total: -, some lines have no digits (see --default)"),
     }
 
-    println!("Part1: {}", sum);
+    Ok(())
 }
 
 fn main() {
-    let input = include_bytes!("../input.txt");
-
-    let input = str::from_utf8(input).unwrap();
-
-    part1(input);
-    part2(input);
+    solution::main_with_modes::<Day1, _>(INPUT, &MODES, parse_explain_args, explain);
 }
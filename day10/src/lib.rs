@@ -0,0 +1,175 @@
+use common::Solution;
+use grid::{Direction, Grid, Point};
+use parsing::ParseError;
+use std::collections::HashSet;
+
+#[derive(Debug, Clone)]
+struct Node {
+    cur: (usize, usize),
+    dist: usize,
+}
+
+impl Node {
+    pub fn new(cur: (usize, usize), dist: usize) -> Node {
+        Node { cur, dist }
+    }
+}
+
+fn get_next_pos(
+    grid: &Grid<char>,
+    pos: (usize, usize),
+    moving_dir: Direction,
+) -> Option<((usize, usize), Direction)> {
+    let new_pos = grid.checked(Point::from(pos).step(moving_dir))?;
+
+    let new_dir = match (moving_dir, grid[new_pos]) {
+        (_, 'S') => moving_dir,
+        (dir, '-') if dir.is_horizontal() => moving_dir,
+        (dir, '|') if !dir.is_horizontal() => moving_dir,
+        (Direction::Right, '7') | (Direction::Left, 'F') => Direction::Down,
+        (Direction::Right, 'J') | (Direction::Left, 'L') => Direction::Up,
+        (Direction::Down, 'J') | (Direction::Up, '7') => Direction::Left,
+        (Direction::Down, 'L') | (Direction::Up, 'F') => Direction::Right,
+        _ => return None,
+    };
+
+    Some((new_pos, new_dir))
+}
+
+// `None` if the pipes from `start` don't lead back to it.
+fn build_path(grid: &Grid<char>, start: (usize, usize)) -> Option<Vec<Node>> {
+    let mut path = Vec::new();
+    let mut dist = 0;
+
+    let initial: Vec<((usize, usize), Direction)> = [
+        Direction::Right,
+        Direction::Left,
+        Direction::Down,
+        Direction::Up,
+    ]
+    .iter()
+    .filter_map(|x| get_next_pos(grid, start, *x))
+    .collect();
+
+    let (mut pos, mut moving_dir) = initial.first()?;
+    path.push(Node::new(start, 0));
+
+    while pos != start {
+        let (new_pos, new_moving_dir) = get_next_pos(grid, pos, moving_dir)?;
+
+        dist += 1;
+        path.push(Node::new(pos, dist));
+
+        pos = new_pos;
+        moving_dir = new_moving_dir;
+    }
+
+    Some(path)
+}
+
+// polygon ray casting algorithm
+fn count_invs(grid: &Grid<char>, pos: (usize, usize), path: &HashSet<(usize, usize)>) -> usize {
+    let (row, end) = pos;
+    let mut count = 0;
+    // go to the right
+    for (col, &c) in grid.row(row)[..end].iter().enumerate() {
+        if !path.contains(&(row, col)) {
+            continue;
+        }
+        if c == 'J' || c == 'L' || c == '|' {
+            count += 1;
+        }
+    }
+
+    count
+}
+
+/// The tiles, and the loop of pipes through the start.
+pub struct Maze {
+    grid: Grid<char>,
+    path: Vec<Node>,
+}
+
+fn parse_maze(input: &str) -> Result<Maze, ParseError> {
+    let grid = Grid::parse_only(input, "|-LJ7F.S")?;
+
+    let mut starts = input.match_indices('S').map(|(i, _)| &input[i..i + 1]);
+    let start_text = starts
+        .next()
+        .ok_or_else(|| ParseError::at(input, &input[input.len()..], "expected a start 'S'"))?;
+    if let Some(again) = starts.next() {
+        return Err(ParseError::at(input, again, "expected only one start 'S'"));
+    }
+
+    let start = grid.position(|&c| c == 'S').expect("the input has an 'S'");
+    let path = build_path(&grid, start)
+        .ok_or_else(|| ParseError::at(input, start_text, "expected a loop of pipes through 'S'"))?;
+
+    Ok(Maze { grid, path })
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    type Input = Maze;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Maze, ParseError> {
+        parse_maze(input)
+    }
+
+    fn part1(maze: &Maze) -> usize {
+        let max = maze.path.iter().map(|x| x.dist).max().unwrap();
+
+        max.div_ceil(2)
+    }
+
+    fn part2(maze: &Maze) -> usize {
+        let grid = &maze.grid;
+        let path: HashSet<(usize, usize)> = HashSet::from_iter(maze.path.iter().map(|n| n.cur));
+
+        grid.positions()
+            .filter(|pos| !path.contains(pos) && count_invs(grid, *pos, &path) % 2 == 1)
+            .count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+    const EXAMPLE2: &str = include_str!("../example2.txt");
+    const EXAMPLE3: &str = include_str!("../example3.txt");
+    const EXAMPLE4: &str = include_str!("../example4.txt");
+    const EXAMPLE5: &str = include_str!("../example5.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day10::part1(&Day10::parse(EXAMPLE).unwrap()), 4);
+        assert_eq!(Day10::part1(&Day10::parse(EXAMPLE2).unwrap()), 8);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day10::part2(&Day10::parse(EXAMPLE3).unwrap()), 4);
+        assert_eq!(Day10::part2(&Day10::parse(EXAMPLE4).unwrap()), 8);
+        assert_eq!(Day10::part2(&Day10::parse(EXAMPLE5).unwrap()), 10);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let err = Day10::parse("...\n...\n").err().unwrap();
+        assert_eq!((err.line, err.reason.as_str()), (3, "expected a start 'S'"));
+
+        let err = Day10::parse(".S.\n..S\n").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 3));
+        assert_eq!(err.reason, "expected only one start 'S'");
+
+        let err = Day10::parse("...\n.S-\n...\n").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 2));
+        assert_eq!(err.reason, "expected a loop of pipes through 'S'");
+    }
+}
@@ -0,0 +1,105 @@
+use common::Solution;
+use grid::{Grid, Point};
+use itertools::Itertools;
+use parsing::ParseError;
+
+/// The galaxy map along with its empty rows and columns.
+pub type Image = (Grid<char>, Vec<usize>, Vec<usize>);
+
+fn parse_image(input: &str) -> Result<Image, ParseError> {
+    let img = Grid::parse_only(input, "#.")?;
+    let mut empty_rows = Vec::new();
+    let mut empty_columns = Vec::new();
+
+    for (row, line) in img.iter_rows().enumerate() {
+        if line.iter().all(|&x| x == '.') {
+            empty_rows.push(row);
+        }
+    }
+
+    for col in 0..img.cols() {
+        if img.col(col).all(|&x| x == '.') {
+            empty_columns.push(col);
+        }
+    }
+    Ok((img, empty_rows, empty_columns))
+}
+
+fn get_galaxy_coords(img: &Grid<char>) -> Vec<(usize, usize)> {
+    img.iter()
+        .filter(|(_, &c)| c == '#')
+        .map(|(pos, _)| pos)
+        .collect()
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    type Input = Image;
+    type Part1 = i64;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Image, ParseError> {
+        parse_image(input)
+    }
+
+    fn part1((img, empty_rows, empty_cols): &Image) -> i64 {
+        let mut galaxies = get_galaxy_coords(img);
+
+        for g in galaxies.iter_mut() {
+            let rows_smaller: usize = empty_rows.iter().filter(|&&i| i < g.0).count();
+            let cols_smaller: usize = empty_cols.iter().filter(|&&i| i < g.1).count();
+
+            g.0 += rows_smaller;
+            g.1 += cols_smaller;
+        }
+
+        galaxies
+            .iter()
+            .combinations(2)
+            .map(|pair| {
+                let (a, b) = (pair[0], pair[1]);
+                Point::from(*a).manhattan(Point::from(*b)) as i64
+            })
+            .sum::<i64>()
+    }
+
+    fn part2((img, empty_rows, empty_cols): &Image) -> usize {
+        let mut galaxies = get_galaxy_coords(img);
+
+        for g in galaxies.iter_mut() {
+            let rows_smaller: usize = empty_rows.iter().filter(|&&i| i < g.0).count();
+            let cols_smaller: usize = empty_cols.iter().filter(|&&i| i < g.1).count();
+
+            g.0 += rows_smaller * (10_usize.pow(6) - 1);
+            g.1 += cols_smaller * (10_usize.pow(6) - 1);
+        }
+
+        galaxies
+            .iter()
+            .combinations(2)
+            .map(|pair| {
+                let (a, b) = (pair[0], pair[1]);
+                Point::from(*a).manhattan(Point::from(*b))
+            })
+            .sum::<usize>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day11::part1(&Day11::parse(EXAMPLE).unwrap()), 374);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day11::part2(&Day11::parse(EXAMPLE).unwrap()), 82000210);
+    }
+}
@@ -0,0 +1,137 @@
+use common::Solution;
+use parsing::ParseError;
+use std::collections::HashMap;
+use std::iter::zip;
+
+/// The springs of every row along with the sizes of its damaged groups.
+pub type Records = (Vec<Vec<char>>, Vec<Vec<usize>>);
+
+fn parse_input(input: &str) -> Result<Records, ParseError> {
+    let mut spring_info: Vec<Vec<char>> = Vec::new();
+    let mut groups = Vec::new();
+
+    for l in input.lines() {
+        let (springs, sizes) = parsing::split_once(input, l, " ")?;
+        if let Some(i) = springs.find(|c| !".#?".contains(c)) {
+            return Err(ParseError::at(
+                input,
+                &springs[i..],
+                "expected '.', '#' or '?'",
+            ));
+        }
+        spring_info.push(springs.chars().collect());
+        groups.push(parsing::list(input, sizes, ",")?);
+    }
+
+    Ok((spring_info, groups))
+}
+
+// index of current line, index of current block, current spring len
+fn get_arrangements_line(
+    line: &Vec<char>,
+    groups: &Vec<usize>,
+    l_i: usize,
+    g_i: usize,
+    s_len: usize,
+    state: &mut HashMap<(usize, usize, usize), usize>,
+) -> usize {
+    if let Some(&cnt) = state.get(&(l_i, g_i, s_len)) {
+        return cnt;
+    }
+    if l_i == line.len() {
+        return if (g_i == groups.len() && s_len == 0)
+            || (g_i == groups.len() - 1 && s_len == groups[g_i])
+        {
+            1
+        } else {
+            0
+        };
+    }
+
+    let mut ret = 0;
+
+    let chars = ['.', '#'];
+    let cur = line[l_i];
+
+    for &c in chars.iter() {
+        if cur == c || cur == '?' {
+            if c == '.' && s_len == 0 {
+                ret += get_arrangements_line(line, groups, l_i + 1, g_i, 0, state);
+            } else if c == '.' && s_len > 0 && g_i < groups.len() && groups[g_i] == s_len {
+                ret += get_arrangements_line(line, groups, l_i + 1, g_i + 1, 0, state);
+            } else if c == '#' {
+                ret += get_arrangements_line(line, groups, l_i + 1, g_i, s_len + 1, state)
+            }
+        }
+    }
+
+    state.insert((l_i, g_i, s_len), ret);
+
+    ret
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    type Input = Records;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1((infos, groups): &Self::Input) -> usize {
+        let mut state = HashMap::new();
+
+        zip(infos, groups)
+            .map(|(info, group)| {
+                state.clear();
+                get_arrangements_line(info, group, 0, 0, 0, &mut state)
+            })
+            .sum()
+    }
+
+    fn part2((infos, groups): &Self::Input) -> usize {
+        let mut sum = 0;
+        let mut state = HashMap::new();
+
+        for (info, group) in zip(infos, groups) {
+            let mut new_info = Vec::new();
+            let mut other = info.clone();
+            other.push('?');
+            for _ in 0..4 {
+                new_info.extend(other.clone());
+            }
+            new_info.extend(info);
+
+            let mut new_group = Vec::new();
+            for _ in 0..5 {
+                new_group.extend(group.clone());
+            }
+
+            sum += get_arrangements_line(&new_info, &new_group, 0, 0, 0, &mut state);
+            state.clear();
+        }
+
+        sum
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day12::part1(&Day12::parse(EXAMPLE).unwrap()), 21);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day12::part2(&Day12::parse(EXAMPLE).unwrap()), 525152);
+    }
+}
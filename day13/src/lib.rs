@@ -0,0 +1,81 @@
+use common::Solution;
+use grid::Grid;
+use parsing::ParseError;
+
+// Number of rows above the first horizontal line of reflection. In part 2
+// the reflection has to be off by exactly one smudge.
+fn get_horizontal_reflection(grid: &Grid<char>, part2: bool) -> Option<i32> {
+    let rows = grid.rows() as i32;
+
+    for r in 0..rows - 1 {
+        let mut diffs = 0x0;
+        for dr in 0..rows - 1 {
+            let above = r - dr;
+            let below = r + 1_i32 + dr;
+            if above >= 0 && below < rows {
+                let above = grid.row(above as usize);
+                let below = grid.row(below as usize);
+                diffs += above.iter().zip(below).filter(|(a, b)| a != b).count();
+            }
+        }
+
+        if diffs == 0 && !part2 || diffs == 1 && part2 {
+            return Some(r + 1);
+        }
+    }
+
+    None
+}
+
+// The example has patterns where a smudge could be fixed in two ways, the
+// horizontal line nearest the top wins.
+fn summarize(grid: &Grid<char>, part2: bool) -> i32 {
+    get_horizontal_reflection(grid, part2)
+        .map(|rows| 100 * rows)
+        .or_else(|| get_horizontal_reflection(&grid.transpose(), part2))
+        .unwrap_or(0)
+}
+
+fn parse_input(input: &str) -> Result<Vec<Grid<char>>, ParseError> {
+    parsing::blocks(input)
+        .map(|pattern| Grid::parse_only(pattern, "#.").map_err(|err| err.within(input, pattern)))
+        .collect()
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    type Input = Vec<Grid<char>>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Vec<Grid<char>>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(grids: &Vec<Grid<char>>) -> i32 {
+        grids.iter().map(|g| summarize(g, false)).sum()
+    }
+
+    fn part2(grids: &Vec<Grid<char>>) -> i32 {
+        grids.iter().map(|g| summarize(g, true)).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day13::part1(&Day13::parse(EXAMPLE).unwrap()), 405);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day13::part2(&Day13::parse(EXAMPLE).unwrap()), 400);
+    }
+}
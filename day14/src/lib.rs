@@ -0,0 +1,86 @@
+use common::Solution;
+use grid::Grid;
+use parsing::ParseError;
+
+fn do_north_tilt(grid: &mut Grid<char>) {
+    for col in 0..grid.cols() {
+        // where the next rolling rock ends up
+        let mut free = 0;
+        for row in 0..grid.rows() {
+            match grid[(row, col)] {
+                'O' => {
+                    grid[(row, col)] = '.';
+                    grid[(free, col)] = 'O';
+                    free += 1;
+                }
+                '#' => free = row + 1,
+                _ => {}
+            }
+        }
+    }
+}
+
+// Tilting north and turning the platform clockwise four times rolls the
+// rocks north, west, south and east, and leaves it facing north again.
+fn do_spin(grid: &Grid<char>) -> Grid<char> {
+    let mut grid = grid.clone();
+    for _ in 0..4 {
+        do_north_tilt(&mut grid);
+        grid = grid.rotate_cw();
+    }
+
+    grid
+}
+
+fn get_load(grid: &Grid<char>) -> usize {
+    grid.iter()
+        .filter(|(_, &c)| c == 'O')
+        .map(|((row, _), _)| grid.rows() - row)
+        .sum()
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    type Input = Grid<char>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+        Grid::parse_only(input, "O#.")
+    }
+
+    fn part1(grid: &Grid<char>) -> usize {
+        let mut grid = grid.clone();
+        do_north_tilt(&mut grid);
+
+        get_load(&grid)
+    }
+
+    fn part2(grid: &Grid<char>) -> usize {
+        let mut grid = grid.clone();
+        for _ in 0..1000 {
+            grid = do_spin(&grid);
+        }
+
+        get_load(&grid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day14::part1(&Day14::parse(EXAMPLE).unwrap()), 136);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day14::part2(&Day14::parse(EXAMPLE).unwrap()), 64);
+    }
+}
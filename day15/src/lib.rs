@@ -0,0 +1,128 @@
+use common::Solution;
+use parsing::ParseError;
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+
+// Every step is either `<label>=<focal length>` or `<label>-`. Newlines are
+// ignored.
+fn parse_input(input: &str) -> Result<Vec<String>, ParseError> {
+    input
+        .split(',')
+        .map(|step| {
+            let clean = step.replace('\n', "");
+            let valid = match clean.split_once('=') {
+                Some((_, focal)) => focal.parse::<u8>().is_ok(),
+                None => clean.ends_with('-'),
+            };
+
+            match valid {
+                true => Ok(clean),
+                false => Err(ParseError::at(
+                    input,
+                    step,
+                    "expected <label>=<focal length> or <label>-",
+                )),
+            }
+        })
+        .collect()
+}
+
+fn hash(input: &str) -> u32 {
+    input.chars().fold(0, |acc, x| {
+        let mut tmp = acc + x as u8 as u32;
+        tmp *= 17;
+        tmp %= 256;
+
+        tmp
+    })
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    type Input = Vec<String>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Vec<String>) -> u32 {
+        input.iter().map(|x| hash(x)).sum()
+    }
+
+    fn part2(input: &Vec<String>) -> u32 {
+        let mut map: HashMap<u32, Vec<(String, u8)>> = HashMap::new();
+        for inp in input.iter() {
+            match inp.contains("=") {
+                true => {
+                    let parts: Vec<&str> = inp.split("=").collect();
+                    let label = parts[0].to_string();
+                    let s = parts[1].parse::<u8>().unwrap();
+
+                    match map.entry(hash(&label)) {
+                        Entry::Occupied(mut entry) => {
+                            if let Some(pos) = entry.get().iter().position(|x| x.0 == label) {
+                                entry.get_mut()[pos].1 = s;
+                            } else {
+                                entry.get_mut().push((label, s))
+                            }
+                        }
+                        Entry::Vacant(entry) => {
+                            entry.insert(vec![(label, s)]);
+                        }
+                    }
+                }
+                false => {
+                    let parts: Vec<&str> = inp.split("-").collect();
+                    let label = parts[0].to_string();
+                    if let Some(val) = map.get_mut(&hash(&label)) {
+                        if let Some(pos) = val.iter().position(|x| x.0 == label) {
+                            val.remove(pos);
+                        }
+                    }
+                }
+            }
+        }
+
+        let mut sum = 0x0;
+        for i in 0..256 {
+            if let Some(val) = map.get(&i) {
+                for (j, (_, s)) in val.iter().enumerate() {
+                    sum += (i + 1) * (j as u32 + 1) * *s as u32;
+                }
+            }
+        }
+
+        sum
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day15::part1(&Day15::parse(EXAMPLE).unwrap()), 1320);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day15::part2(&Day15::parse(EXAMPLE).unwrap()), 145);
+    }
+
+    #[test]
+    fn hash_steps() {
+        assert_eq!(hash("HASH"), 52);
+        assert_eq!(hash("rn=1"), 30);
+        assert_eq!(hash("cm-"), 253);
+        assert_eq!(hash("rn"), 0);
+        assert_eq!(hash("qp"), 1);
+        assert_eq!(hash(""), 0);
+    }
+}
@@ -0,0 +1,142 @@
+use common::Solution;
+use grid::{Direction, Grid, Point};
+use parsing::ParseError;
+use std::cmp;
+use std::collections::{HashSet, VecDeque};
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+struct Beam {
+    moving_dir: Direction,
+    pos: Point,
+}
+
+impl Beam {
+    pub fn new(moving_dir: Direction, pos: Point) -> Beam {
+        Beam { moving_dir, pos }
+    }
+
+    pub fn step(&mut self) {
+        self.pos = self.pos.step(self.moving_dir);
+    }
+
+    fn turned(&self, moving_dir: Direction) -> Beam {
+        Beam::new(moving_dir, self.pos)
+    }
+}
+
+fn get_energized_amount(grid: &Grid<char>, start_beam: Beam) -> usize {
+    let mut energized_map = HashSet::new();
+    let mut work = VecDeque::new();
+
+    work.push_front(start_beam);
+
+    let mut start = true;
+
+    while let Some(mut beam) = work.pop_front() {
+        if !start {
+            beam.step();
+        } else {
+            start = false;
+        }
+
+        let Some(&c) = grid.get(beam.pos) else {
+            continue;
+        };
+
+        // seen a beam with same pos and moving dir before so can't give us new info
+        if !energized_map.insert(beam.clone()) {
+            continue;
+        }
+
+        let dir = beam.moving_dir;
+        match c {
+            '|' if dir.is_horizontal() => {
+                work.push_back(beam.turned(Direction::Up));
+                work.push_back(beam.turned(Direction::Down));
+            }
+            '-' if !dir.is_horizontal() => {
+                work.push_back(beam.turned(Direction::Left));
+                work.push_back(beam.turned(Direction::Right));
+            }
+            '/' if dir.is_horizontal() => work.push_back(beam.turned(dir.turn_left())),
+            '/' => work.push_back(beam.turned(dir.turn_right())),
+            '\\' if dir.is_horizontal() => work.push_back(beam.turned(dir.turn_right())),
+            '\\' => work.push_back(beam.turned(dir.turn_left())),
+            '.' | '|' | '-' => work.push_back(beam),
+            _ => panic!("Unexpected token: {}", c),
+        }
+    }
+
+    let res: HashSet<_> = energized_map.iter().map(|x| x.pos).collect();
+    res.len()
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    type Input = Grid<char>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+        Grid::parse_only(input, ".|-/\\")
+    }
+
+    fn part1(grid: &Grid<char>) -> usize {
+        let beam = Beam::new(Direction::Right, Point::new(0, 0));
+
+        get_energized_amount(grid, beam)
+    }
+
+    fn part2(grid: &Grid<char>) -> usize {
+        let rows = grid.rows() as isize;
+        let cols = grid.cols() as isize;
+        let mut max = 0;
+
+        // top & bottom
+        for i in 0..cols {
+            max = cmp::max(
+                max,
+                get_energized_amount(grid, Beam::new(Direction::Down, Point::new(0, i))),
+            );
+
+            max = cmp::max(
+                max,
+                get_energized_amount(grid, Beam::new(Direction::Up, Point::new(rows - 1, i))),
+            );
+        }
+
+        // left & right
+        for i in 0..rows {
+            max = cmp::max(
+                max,
+                get_energized_amount(grid, Beam::new(Direction::Right, Point::new(i, 0))),
+            );
+
+            max = cmp::max(
+                max,
+                get_energized_amount(grid, Beam::new(Direction::Left, Point::new(i, cols - 1))),
+            )
+        }
+
+        max
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day16::part1(&Day16::parse(EXAMPLE).unwrap()), 46);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day16::part2(&Day16::parse(EXAMPLE).unwrap()), 51);
+    }
+}
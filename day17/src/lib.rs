@@ -0,0 +1,117 @@
+use common::Solution;
+use grid::{Direction, Grid, Point};
+use parsing::ParseError;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashSet};
+
+fn parse_input(input: &str) -> Result<Grid<usize>, ParseError> {
+    Grid::parse_with(input, |c| c.to_digit(10).map(|d| d as usize))
+}
+
+#[derive(Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+struct Path {
+    loss: usize,
+    pos: Point,
+    dir: Direction,
+    sid: usize,
+}
+
+impl Path {
+    pub fn new(pos: Point, dir: Direction, sid: usize, loss: usize) -> Path {
+        Path {
+            loss,
+            pos,
+            dir,
+            sid,
+        }
+    }
+
+    pub fn step(&mut self) {
+        self.pos = self.pos.step(self.dir);
+        self.sid += 1;
+    }
+}
+
+fn dijkstra(grid: &Grid<usize>, part2: bool) -> usize {
+    let mut loss_map = Grid::new(grid.rows(), grid.cols(), usize::MAX);
+    // min heap
+    let mut work = BinaryHeap::new();
+
+    loss_map[(0, 0)] = 0;
+
+    Direction::ALL
+        .iter()
+        .for_each(|&d| work.push(Reverse(Path::new(Point::new(0, 0), d, 0, 0))));
+
+    let mut seen = HashSet::new();
+
+    while let Some(Reverse(mut path)) = work.pop() {
+        path.step();
+
+        let key = (path.pos, path.dir, path.sid);
+
+        let Some(pos) = grid.checked(path.pos) else {
+            continue;
+        };
+        if !part2 && path.sid > 3 || part2 && path.sid > 10 || seen.contains(&key) {
+            continue;
+        }
+
+        seen.insert(key);
+
+        path.loss += grid[pos];
+        // an ultra crucible can't stop before it has moved four blocks
+        if (!part2 || path.sid >= 4) && path.loss < loss_map[pos] {
+            loss_map[pos] = path.loss;
+        }
+
+        if !part2 || path.sid >= 4 {
+            for d in [path.dir.turn_left(), path.dir.turn_right()] {
+                work.push(Reverse(Path::new(path.pos, d, 0, path.loss)))
+            }
+        }
+        work.push(Reverse(path));
+    }
+
+    loss_map[(grid.rows() - 1, grid.cols() - 1)]
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+    type Input = Grid<usize>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Grid<usize>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(grid: &Grid<usize>) -> usize {
+        dijkstra(grid, false)
+    }
+
+    fn part2(grid: &Grid<usize>) -> usize {
+        dijkstra(grid, true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+    const EXAMPLE2: &str = include_str!("../example2.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day17::part1(&Day17::parse(EXAMPLE).unwrap()), 102);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day17::part2(&Day17::parse(EXAMPLE).unwrap()), 94);
+        assert_eq!(Day17::part2(&Day17::parse(EXAMPLE2).unwrap()), 71);
+    }
+}
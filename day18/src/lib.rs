@@ -0,0 +1,132 @@
+use common::Solution;
+use geo::{area::Area, LineString, Polygon};
+use grid::{Direction, Point};
+use parsing::ParseError;
+use std::collections::HashMap;
+
+/// Number of boundary points and the corners of the trench.
+pub type Trench = (usize, Vec<Point>);
+
+fn parse_step(input: &str, line: &str, part2: bool) -> Result<(Direction, usize), ParseError> {
+    let dirs1: HashMap<&str, Direction> = HashMap::from([
+        ("R", Direction::Right),
+        ("L", Direction::Left),
+        ("U", Direction::Up),
+        ("D", Direction::Down),
+    ]);
+
+    let dirs2: HashMap<&str, Direction> = HashMap::from([
+        ("0", Direction::Right),
+        ("2", Direction::Left),
+        ("3", Direction::Up),
+        ("1", Direction::Down),
+    ]);
+
+    let parts: Vec<&str> = line.split(' ').collect();
+    let [dir, amt, color] = parts[..] else {
+        return Err(ParseError::at(
+            input,
+            line,
+            "expected \"<dir> <amount> (#<color>)\"",
+        ));
+    };
+
+    match part2 {
+        true => {
+            let hex = color
+                .strip_prefix("(#")
+                .and_then(|c| c.strip_suffix(')'))
+                .filter(|c| c.len() == 6 && c.is_ascii())
+                .ok_or_else(|| ParseError::at(input, color, "expected \"(#<6 hex digits>)\""))?;
+
+            let dir = &hex[5..];
+            let dir = dirs2
+                .get(dir)
+                .ok_or_else(|| ParseError::at(input, dir, "expected a direction 0-3"))?;
+
+            let amt = &hex[..5];
+            let amt = usize::from_str_radix(amt, 16)
+                .map_err(|_| ParseError::at(input, amt, "expected a hex number"))?;
+
+            Ok((*dir, amt))
+        }
+        false => {
+            let amt = parsing::number(input, amt)?;
+            let dir = dirs1
+                .get(dir)
+                .ok_or_else(|| ParseError::at(input, dir, "expected R, L, U or D"))?;
+            Ok((*dir, amt))
+        }
+    }
+}
+
+fn parse_input(input: &str, part2: bool) -> Result<Trench, ParseError> {
+    let mut loc = Point::default();
+    let mut coords = Vec::new();
+
+    let mut boundary_points = 0x0;
+
+    for line in input.lines() {
+        let (dir, amt) = parse_step(input, line, part2)?;
+
+        boundary_points += amt;
+
+        loc += dir.delta() * amt as isize;
+
+        coords.push(loc);
+    }
+
+    Ok((boundary_points, coords))
+}
+
+// Pick's theorem gives the interior from the shoelace area, the boundary is
+// added back on top.
+fn lagoon_size((boundary_points, coords): &Trench) -> i64 {
+    let coords: Vec<(f64, f64)> = coords
+        .iter()
+        .map(|p| (p.row as f64, p.col as f64))
+        .collect();
+    let poly = Polygon::new(LineString::from(coords), vec![]);
+    let interior_points = poly.unsigned_area() - (*boundary_points as f64 / 2.0) + 1.0;
+
+    (interior_points + *boundary_points as f64) as i64
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+    /// The dig plan read as directions and as hex colours.
+    type Input = [Trench; 2];
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<[Trench; 2], ParseError> {
+        Ok([parse_input(input, false)?, parse_input(input, true)?])
+    }
+
+    fn part1([trench, _]: &[Trench; 2]) -> i64 {
+        lagoon_size(trench)
+    }
+
+    fn part2([_, trench]: &[Trench; 2]) -> i64 {
+        lagoon_size(trench)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day18::part1(&Day18::parse(EXAMPLE).unwrap()), 62);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day18::part2(&Day18::parse(EXAMPLE).unwrap()), 952408144115);
+    }
+}
@@ -0,0 +1,425 @@
+use common::Solution;
+use parsing::ParseError;
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rule {
+    category: String,
+    operation: String,
+    value: isize,
+}
+
+impl Rule {
+    pub fn new(category: String, operation: String, value: isize) -> Rule {
+        Rule {
+            category,
+            operation,
+            value,
+        }
+    }
+
+    fn apply(&self, part: &Part) -> bool {
+        for (category, val) in part.categories.iter() {
+            if *category == self.category {
+                return match self.operation.as_str() {
+                    "<" => *val < self.value,
+                    ">" => *val > self.value,
+                    _ => panic!("Unhandled operation in rule: {}", self.operation),
+                };
+            }
+        }
+        false
+    }
+
+    fn invert(&self) -> Rule {
+        let op = match self.operation.as_str() {
+            ">" => "<",
+            "<" => ">",
+            _ => panic!(""),
+        };
+
+        let val = match self.operation.as_str() {
+            "<" => self.value - 1,
+            ">" => self.value + 1,
+            _ => panic!(""),
+        };
+
+        Rule {
+            category: self.category.clone(),
+            operation: op.to_string(),
+            value: val,
+        }
+    }
+
+    fn get_amount_matching(&self) -> usize {
+        match self.operation.as_str() {
+            "<" => self.value as usize - 1,
+            ">" => 4000 - self.value as usize,
+            _ => panic!(""),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Workflow {
+    name: String,
+    rules: Vec<(Option<Rule>, String)>,
+}
+
+impl Workflow {
+    pub fn new(name: String, rules: Vec<(Option<Rule>, String)>) -> Workflow {
+        Workflow { name, rules }
+    }
+
+    fn get_next(&self, part: &Part) -> String {
+        let next = self.rules.iter().find(|r| {
+            if let Some(r) = &r.0 {
+                r.apply(part)
+            } else {
+                true
+            }
+        });
+
+        next.unwrap().1.clone()
+    }
+}
+
+#[derive(Debug)]
+pub struct Part {
+    categories: Vec<(String, isize)>,
+}
+
+impl Part {
+    pub fn new(categories: Vec<(String, isize)>) -> Part {
+        Part { categories }
+    }
+}
+
+// a<2006:qkq
+fn parse_rule<'a>(input: &str, text: &'a str) -> Result<(Option<Rule>, &'a str), ParseError> {
+    let Some((cond, next)) = text.split_once(':') else {
+        return Ok((None, text));
+    };
+
+    let Some(op_idx) = cond.find(['<', '>']) else {
+        return Err(ParseError::at(
+            input,
+            cond,
+            "expected a '<' or '>' condition",
+        ));
+    };
+    let cat = &cond[..op_idx];
+    if !["x", "m", "a", "s"].contains(&cat) {
+        return Err(ParseError::at(input, cat, "expected x, m, a or s"));
+    }
+    let op = cond[op_idx..op_idx + 1].to_string();
+    let val_text = &cond[op_idx + 1..];
+    let val = parsing::number(input, val_text)?;
+    if !(1..=4000).contains(&val) {
+        return Err(ParseError::at(
+            input,
+            val_text,
+            "expected a rating from 1 to 4000",
+        ));
+    }
+
+    Ok((Some(Rule::new(cat.to_string(), op, val)), next))
+}
+
+// px{a<2006:qkq,m>2090:A,rfg}, along with where each rule sends a part
+fn parse_workflow<'a>(input: &str, line: &'a str) -> Result<(Workflow, Vec<&'a str>), ParseError> {
+    let Some(open) = line.find('{') else {
+        return Err(ParseError::at(input, line, "expected \"<name>{<rules>}\""));
+    };
+    let name = line[..open].to_string();
+    let rules: Vec<(Option<Rule>, &str)> = parsing::delimited(input, &line[open..], "{", "}")?
+        .split(',')
+        .map(|rule| parse_rule(input, rule))
+        .collect::<Result<_, _>>()?;
+
+    // Every part has to go somewhere: the last rule, and only that one,
+    // takes whatever is left.
+    let last = rules.len() - 1;
+    for (i, (rule, next)) in rules.iter().enumerate() {
+        if rule.is_none() && i != last {
+            let reason = "expected only the last rule to have no condition";
+            return Err(ParseError::at(input, next, reason));
+        }
+        if rule.is_some() && i == last {
+            let reason = "expected the last rule to have no condition";
+            return Err(ParseError::at(input, next, reason));
+        }
+    }
+
+    let targets = rules.iter().map(|&(_, next)| next).collect();
+    let rules = rules
+        .into_iter()
+        .map(|(rule, next)| (rule, next.to_string()))
+        .collect();
+    Ok((Workflow::new(name, rules), targets))
+}
+
+// Every part starting at "in" has to end up in A or R: the workflows it can
+// be sent to have to exist, and none of them may lead back to itself.
+fn check_workflows(
+    input: &str,
+    block: &str,
+    targets: &HashMap<&str, Vec<&str>>,
+) -> Result<(), ParseError> {
+    fn visit<'a>(
+        input: &str,
+        name: &'a str,
+        targets: &HashMap<&str, Vec<&'a str>>,
+        path: &mut Vec<&'a str>,
+        done: &mut HashSet<&'a str>,
+    ) -> Result<(), ParseError> {
+        path.push(name);
+        for &next in targets[name].iter() {
+            if next == "A" || next == "R" || done.contains(next) {
+                continue;
+            }
+            if !targets.contains_key(next) {
+                return Err(ParseError::at(input, next, "expected a workflow, A or R"));
+            }
+            if path.contains(&next) {
+                let reason = format!("expected workflows not to loop back to {}", next);
+                return Err(ParseError::at(input, next, reason));
+            }
+            visit(input, next, targets, path, done)?;
+        }
+        path.pop();
+        done.insert(name);
+
+        Ok(())
+    }
+
+    if !targets.contains_key("in") {
+        return Err(ParseError::at(input, block, "expected a workflow named in"));
+    }
+    visit(input, "in", targets, &mut Vec::new(), &mut HashSet::new())
+}
+
+// {x=787,m=2655,a=1222,s=2876}
+fn parse_part(input: &str, line: &str) -> Result<Part, ParseError> {
+    let categories = parsing::delimited(input, line, "{", "}")?;
+    let categories = parsing::key_values(input, categories, ",", "=")?
+        .into_iter()
+        .map(|(name, val)| (name.to_string(), val))
+        .collect();
+
+    Ok(Part::new(categories))
+}
+
+fn parse_input(input: &str) -> Result<(HashMap<String, Workflow>, Vec<Part>), ParseError> {
+    let mut blocks = parsing::blocks(input);
+    let (Some(workflows), Some(parts), None) = (blocks.next(), blocks.next(), blocks.next()) else {
+        let reason = "expected workflows and parts separated by a blank line";
+        return Err(ParseError::at(input, input, reason));
+    };
+
+    let mut workflow_map = HashMap::new();
+    let mut targets = HashMap::new();
+    for line in workflows.lines() {
+        let (flow, next) = parse_workflow(input, line)?;
+        let name = &line[..flow.name.len()];
+        if targets.insert(name, next).is_some() {
+            let reason = format!("expected only one workflow named {}", name);
+            return Err(ParseError::at(input, name, reason));
+        }
+        workflow_map.insert(flow.name.clone(), flow);
+    }
+    check_workflows(input, workflows, &targets)?;
+
+    let parts = parts
+        .lines()
+        .map(|l| parse_part(input, l))
+        .collect::<Result<_, _>>()?;
+
+    Ok((workflow_map, parts))
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+    type Input = (HashMap<String, Workflow>, Vec<Part>);
+    type Part1 = isize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1((workflows, parts): &Self::Input) -> isize {
+        let mut sum = 0x0;
+        for part in parts.iter() {
+            let mut cur = &workflows["in"];
+            loop {
+                let next = cur.get_next(part);
+
+                if next == "A" {
+                    sum += part.categories.iter().map(|c| c.1).sum::<isize>();
+                }
+
+                if next == "A" || next == "R" {
+                    break;
+                }
+
+                cur = &workflows[&next];
+            }
+        }
+
+        sum
+    }
+
+    fn part2((workflows, _): &Self::Input) -> usize {
+        let mut work = VecDeque::new();
+        let mut solutions: Vec<Vec<Rule>> = Vec::new();
+        work.push_back((&workflows["in"], Vec::new()));
+        while let Some((workflow, rules)) = work.pop_front() {
+            for (rule, next) in workflow.rules.iter() {
+                if let Some(rule) = &rule {
+                    let mut new_rules = rules.clone();
+                    // all rules until now did not match
+                    for (rule2, _) in workflow.rules.iter() {
+                        let rule2 = rule2.as_ref().unwrap();
+                        if *rule2 == *rule {
+                            break;
+                        }
+
+                        new_rules.push(rule2.invert());
+                    }
+                    new_rules.push(rule.clone());
+
+                    if next == "A" {
+                        solutions.push(new_rules);
+                    } else if next != "R" {
+                        work.push_back((&workflows[next], new_rules))
+                    }
+                } else {
+                    let mut new_rules = rules.clone();
+                    for (rule, _) in workflow.rules.iter() {
+                        if let Some(rule) = rule {
+                            new_rules.push(rule.invert())
+                        }
+                    }
+                    if next == "A" {
+                        solutions.push(new_rules.clone());
+                    } else if next != "R" {
+                        // none of the rules matched
+                        work.push_back((&workflows[next], new_rules))
+                    }
+                }
+            }
+        }
+
+        let mut new_solutions: Vec<Vec<usize>> = Vec::new();
+        let categories = ["x", "m", "a", "s"];
+        for sol in solutions.iter() {
+            let mut solution: Vec<usize> = Vec::new();
+            for &cat in categories.iter() {
+                let same_category: Vec<&Rule> =
+                    sol.iter().filter(|&x| x.category.as_str() == cat).collect();
+                if same_category.len() > 1 {
+                    let mut bigger: Vec<&&Rule> = same_category
+                        .iter()
+                        .filter(|&x| x.operation == ">")
+                        .collect();
+
+                    // descending
+                    bigger.sort_by_key(|a| Reverse(a.value));
+
+                    let mut smaller: Vec<&&Rule> = same_category
+                        .iter()
+                        .filter(|&x| x.operation == "<")
+                        .collect();
+
+                    // ascending
+                    smaller.sort_by_key(|a| a.value);
+
+                    if !bigger.is_empty() && !smaller.is_empty() {
+                        // no rating fits if the bounds cross
+                        let between = smaller[0].value - bigger[0].value - 1;
+                        solution.push(between.max(0) as usize);
+                    } else if !bigger.is_empty() {
+                        solution.push(bigger[0].get_amount_matching())
+                    } else if !smaller.is_empty() {
+                        solution.push(smaller[0].get_amount_matching())
+                    }
+                } else if same_category.len() == 1 {
+                    solution.push(same_category[0].get_amount_matching());
+                } else {
+                    solution.push(4000);
+                }
+            }
+            assert!(solution.len() == 4);
+            new_solutions.push(solution)
+        }
+
+        let sum = new_solutions
+            .iter()
+            .map(|s| s.iter().product::<usize>())
+            .sum::<usize>();
+
+        sum
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day19::part1(&Day19::parse(EXAMPLE).unwrap()), 19114);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(
+            Day19::part2(&Day19::parse(EXAMPLE).unwrap()),
+            167409079868000
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        let err = |text: &str| Day19::parse(text).err().unwrap();
+        let part = "\n\n{x=1,m=2,a=9,s=4}\n";
+
+        let e = err(&format!("in{{a<5:A}}{}", part));
+        assert_eq!(
+            (e.column, e.reason.as_str()),
+            (8, "expected the last rule to have no condition")
+        );
+        let e = err(&format!("in{{A,a<5:R}}{}", part));
+        assert_eq!(e.reason, "expected only the last rule to have no condition");
+        let e = err(&format!("in{{a<5:px,R}}{}", part));
+        assert_eq!(
+            (e.column, e.reason.as_str()),
+            (8, "expected a workflow, A or R")
+        );
+        let e = err(&format!("px{{A}}{}", part));
+        assert_eq!(e.reason, "expected a workflow named in");
+        let e = err(&format!("in{{a<5:px,A}}\npx{{in}}{}", part));
+        assert_eq!(
+            (e.line, e.reason.as_str()),
+            (2, "expected workflows not to loop back to in")
+        );
+        let e = err(&format!("in{{A}}\nin{{R}}{}", part));
+        assert_eq!(e.reason, "expected only one workflow named in");
+        let e = err(&format!("in{{q<5:A,R}}{}", part));
+        assert_eq!(e.reason, "expected x, m, a or s");
+        let e = err(&format!("in{{x>0:A,R}}{}", part));
+        assert_eq!(e.reason, "expected a rating from 1 to 4000");
+    }
+
+    #[test]
+    fn crossing_bounds() {
+        let input = "in{x>100:a,R}\na{x<50:A,R}\n\n{x=1,m=2,a=9,s=4}\n";
+        assert_eq!(Day19::part2(&Day19::parse(input).unwrap()), 0);
+    }
+}
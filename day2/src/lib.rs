@@ -0,0 +1,306 @@
+use common::Solution;
+use parsing::ParseError;
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::fmt;
+
+/// A cube color, any name the input uses.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Color(pub String);
+
+impl From<&str> for Color {
+    fn from(name: &str) -> Color {
+        Color(name.to_string())
+    }
+}
+
+impl Borrow<str> for Color {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// How many cubes of each color there are, colors that aren't in it have
+/// none.
+pub type Bag = HashMap<Color, u32>;
+
+/// The bag part 1 asks about.
+pub fn puzzle_bag() -> Bag {
+    [("red", 12), ("green", 13), ("blue", 14)]
+        .into_iter()
+        .map(|(color, n)| (Color::from(color), n))
+        .collect()
+}
+
+// 3 blue, 4 red
+fn parse_cubes(input: &str, text: &str, bag: &mut Bag) -> Result<(), ParseError> {
+    for cubes in text.split(',') {
+        let (amt, color) = parsing::split_once(input, cubes.trim(), " ")?;
+        if color.is_empty() || !color.chars().all(char::is_alphabetic) {
+            return Err(ParseError::at(input, color, "expected a color"));
+        }
+        let amt: u32 = parsing::number(input, amt)?;
+        *bag.entry(Color::from(color)).or_default() += amt;
+    }
+
+    Ok(())
+}
+
+/// Parses a bag like `12 red, 13 green, 14 blue`, the cubes may also be
+/// spread over several lines.
+pub fn parse_bag(input: &str) -> Result<Bag, ParseError> {
+    let mut bag = Bag::new();
+    for line in input.lines().filter(|line| !line.trim().is_empty()) {
+        parse_cubes(input, line, &mut bag)?;
+    }
+
+    Ok(bag)
+}
+
+pub fn format_bag(bag: &Bag) -> String {
+    let mut cubes: Vec<_> = bag.iter().collect();
+    cubes.sort();
+    cubes
+        .iter()
+        .map(|(color, amt)| format!("{} {}", amt, color))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// The product of the amounts of `colors` in `bag`.
+pub fn power(bag: &Bag, colors: &[&str]) -> u32 {
+    colors
+        .iter()
+        .map(|&c| bag.get(c).copied().unwrap_or(0))
+        .product()
+}
+
+/// The cubes shown in one round of a game.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Round {
+    cubes: Bag,
+}
+
+impl Round {
+    pub fn cubes(&self) -> &Bag {
+        &self.cubes
+    }
+
+    pub fn count(&self, color: &str) -> u32 {
+        self.cubes.get(color).copied().unwrap_or(0)
+    }
+
+    pub fn total(&self) -> u32 {
+        self.cubes.values().sum()
+    }
+}
+
+/// Why a game isn't possible with some bag. `round` counts from 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation {
+    pub round: usize,
+    pub color: Color,
+    pub drawn: u32,
+    pub limit: u32,
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "round {} drew {} {}, the bag has {}",
+            self.round, self.drawn, self.color, self.limit
+        )
+    }
+}
+
+pub struct Game {
+    id: u32,
+    rounds: Vec<Round>,
+}
+
+impl Game {
+    /// Parses a `Game <id>: <amount> <color>, ...; ...` line of `input`.
+    pub fn parse(input: &str, line: &str) -> Result<Game, ParseError> {
+        let (game, rounds_str) = parsing::split_once(input, line, ":")?;
+        let id = parsing::number(input, parsing::prefixed(input, game, "Game ")?)?;
+
+        let mut rounds = Vec::new();
+        for round in rounds_str.split(';') {
+            let mut cubes = Bag::new();
+            parse_cubes(input, round, &mut cubes)?;
+            rounds.push(Round { cubes });
+        }
+
+        Ok(Game { id, rounds })
+    }
+
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    pub fn rounds(&self) -> &[Round] {
+        &self.rounds
+    }
+
+    /// How many cubes each round showed in all.
+    pub fn round_totals(&self) -> Vec<u32> {
+        self.rounds.iter().map(Round::total).collect()
+    }
+
+    /// The first round that shows more cubes of a color than `bag` has. Of
+    /// several colors in that round the first by name is reported.
+    pub fn violation(&self, bag: &Bag) -> Option<Violation> {
+        self.rounds.iter().enumerate().find_map(|(i, round)| {
+            let mut cubes: Vec<_> = round.cubes.iter().collect();
+            cubes.sort();
+            cubes.into_iter().find_map(|(color, &drawn)| {
+                let limit = bag.get(color).copied().unwrap_or(0);
+                (drawn > limit).then(|| Violation {
+                    round: i + 1,
+                    color: color.clone(),
+                    drawn,
+                    limit,
+                })
+            })
+        })
+    }
+
+    pub fn is_possible(&self, bag: &Bag) -> bool {
+        self.violation(bag).is_none()
+    }
+
+    /// The most cubes of each color any round showed, which is also the
+    /// fewest the game could have been played with.
+    pub fn minimal_bag(&self) -> Bag {
+        let mut bag = Bag::new();
+        for (color, &amt) in self.rounds.iter().flat_map(|r| r.cubes.iter()) {
+            let max = bag.entry(color.clone()).or_default();
+            *max = (*max).max(amt);
+        }
+
+        bag
+    }
+}
+
+/// The games that could have been played with `bag`.
+pub fn possible_games<'a>(games: &'a [Game], bag: &'a Bag) -> impl Iterator<Item = &'a Game> {
+    games.iter().filter(|g| g.is_possible(bag))
+}
+
+/// The smallest bag every game could have been played with.
+pub fn minimal_bag(games: &[Game]) -> Bag {
+    let mut bag = Bag::new();
+    for (color, amt) in games.iter().flat_map(|g| g.minimal_bag()) {
+        let max = bag.entry(color).or_default();
+        *max = (*max).max(amt);
+    }
+
+    bag
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+    type Input = Vec<Game>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Vec<Game>, ParseError> {
+        input.lines().map(|line| Game::parse(input, line)).collect()
+    }
+
+    fn part1(games: &Vec<Game>) -> u32 {
+        possible_games(games, &puzzle_bag()).map(|g| g.id).sum()
+    }
+
+    fn part2(games: &Vec<Game>) -> u32 {
+        games
+            .iter()
+            .map(|g| power(&g.minimal_bag(), &["red", "green", "blue"]))
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day2::part1(&Day2::parse(EXAMPLE).unwrap()), 8);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day2::part2(&Day2::parse(EXAMPLE).unwrap()), 2286);
+    }
+
+    #[test]
+    fn what_if_bags() {
+        let games = Day2::parse(EXAMPLE).unwrap();
+        let ids = |bag: &Bag| {
+            possible_games(&games, bag)
+                .map(Game::id)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(ids(&puzzle_bag()), [1, 2, 5]);
+        assert_eq!(
+            ids(&parse_bag("20 red, 13 green\n15 blue\n").unwrap()),
+            [1, 2, 3, 4, 5]
+        );
+        assert_eq!(
+            ids(&parse_bag("5 red, 9 green, 9 blue, 9 purple").unwrap()),
+            [1, 2]
+        );
+        assert_eq!(ids(&Bag::new()), [] as [u32; 0]);
+
+        let minimal = minimal_bag(&games);
+        assert_eq!(format_bag(&minimal), "15 blue, 13 green, 20 red");
+        assert_eq!(ids(&minimal), [1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn other_colors() {
+        let input = "Game 1: 2 purple, 1 red; 5 purple\nGame 2: 1 teal\n";
+        let games = Day2::parse(input).unwrap();
+        assert_eq!(format_bag(&games[0].minimal_bag()), "5 purple, 1 red");
+        assert_eq!(power(&games[0].minimal_bag(), &["red", "purple"]), 5);
+        assert_eq!(power(&games[1].minimal_bag(), &["red", "green", "blue"]), 0);
+
+        let err = parse_bag("1 red, 2 gr3en").unwrap_err();
+        assert_eq!((err.column, err.text.as_str()), (10, "gr3en"));
+    }
+
+    #[test]
+    fn rounds() {
+        let games = Day2::parse(EXAMPLE).unwrap();
+        let game = &games[2];
+        assert_eq!(game.rounds().len(), 3);
+        assert_eq!(game.rounds()[1].count("green"), 13);
+        assert_eq!(game.rounds()[1].count("purple"), 0);
+        assert_eq!(game.round_totals(), [34, 22, 6]);
+
+        let violation = game.violation(&puzzle_bag()).unwrap();
+        assert_eq!(violation.to_string(), "round 1 drew 20 red, the bag has 12");
+        assert_eq!(games[3].violation(&puzzle_bag()).unwrap().round, 3);
+        assert_eq!(games[0].violation(&puzzle_bag()), None);
+
+        let game = Game::parse(
+            "Game 9: 1 red, 2 red; 3 blue",
+            "Game 9: 1 red, 2 red; 3 blue",
+        )
+        .unwrap();
+        assert_eq!(game.rounds()[0].count("red"), 3);
+    }
+}
@@ -0,0 +1,246 @@
+use common::Solution;
+use num::integer::lcm;
+use parsing::ParseError;
+use std::collections::{HashMap, VecDeque};
+#[derive(Clone)]
+pub enum ModuleType {
+    FlipFlop,
+    Conjunction,
+    Broadcast,
+    None,
+}
+#[derive(Clone)]
+pub struct Module {
+    name: String,
+    typ: ModuleType,
+    dests: Vec<String>,
+    inputs: HashMap<String, u8>,
+    state: u8,
+}
+
+impl Module {
+    pub fn new(name: String, typ: ModuleType, dests: Vec<String>) -> Module {
+        Module {
+            name,
+            typ,
+            dests,
+            inputs: HashMap::new(),
+            state: 0,
+        }
+    }
+
+    pub fn receive(&mut self, from: String, pulse: u8, work: &mut VecDeque<(String, String, u8)>) {
+        match self.typ {
+            ModuleType::Broadcast => {
+                for dest in self.dests.iter() {
+                    work.push_back((self.name.clone(), dest.clone(), pulse));
+                }
+            }
+            ModuleType::FlipFlop if pulse == 0 => {
+                self.state ^= 1;
+                for dest in self.dests.iter() {
+                    work.push_back((self.name.clone(), dest.clone(), self.state))
+                }
+            }
+            ModuleType::Conjunction => {
+                if let Some(val) = self.inputs.get_mut(&from) {
+                    *val = pulse;
+                }
+
+                let pulse = if self.inputs.values().all(|&x| x == 1) {
+                    0
+                } else {
+                    1
+                };
+                for dest in self.dests.iter() {
+                    work.push_back((self.name.clone(), dest.clone(), pulse))
+                }
+            }
+            _ => (),
+        }
+    }
+}
+
+// %a -> inv, con
+fn parse_module(input: &str, line: &str) -> Result<Module, ParseError> {
+    let (name, dests) = parsing::split_once(input, line, " -> ")?;
+    let (typ, name) = match name.strip_prefix(['%', '&']) {
+        Some(rest) if name.starts_with('%') => (ModuleType::FlipFlop, rest),
+        Some(rest) => (ModuleType::Conjunction, rest),
+        None if name == "broadcaster" => (ModuleType::Broadcast, name),
+        None => return Err(ParseError::at(input, name, "expected %, & or broadcaster")),
+    };
+    if name.is_empty() {
+        return Err(ParseError::at(input, name, "expected a module name"));
+    }
+    let dests = dests.split(", ").map(|x| x.to_string()).collect();
+
+    Ok(Module::new(name.to_string(), typ, dests))
+}
+
+fn parse_input(input: &str) -> Result<HashMap<String, Module>, ParseError> {
+    let mut modules: Vec<Module> = Vec::new();
+    for line in input.lines() {
+        let module = parse_module(input, line)?;
+        if modules.iter().any(|m| m.name == module.name) {
+            let reason = format!("expected only one module named {}", module.name);
+            return Err(ParseError::at(input, line, reason));
+        }
+        modules.push(module);
+    }
+    if !modules.iter().any(|m| m.name == "broadcaster") {
+        let end = &input[input.len()..];
+        return Err(ParseError::at(input, end, "expected a broadcaster module"));
+    }
+
+    let mut missing = Vec::new();
+    for m in modules.iter() {
+        for n in m.dests.iter() {
+            if modules.iter().find(|x| x.name == *n).is_none() {
+                missing.push(Module::new(n.clone(), ModuleType::None, Vec::new()));
+            }
+        }
+    }
+
+    modules.append(&mut missing);
+    let mut inputs = Vec::new();
+    for m in modules.iter() {
+        for dest in m.dests.iter() {
+            inputs.push((dest.clone(), m.name.clone()));
+        }
+    }
+    let mut modules: HashMap<String, Module> =
+        modules.into_iter().map(|m| (m.name.clone(), m)).collect();
+
+    for (node, inp) in inputs.iter() {
+        let module = modules.get_mut(node).unwrap();
+        module.inputs.insert(inp.clone(), 0);
+    }
+
+    Ok(modules)
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+    type Input = HashMap<String, Module>;
+    type Part1 = usize;
+    // `None` unless "rx" is fed by a single conjunction, see below.
+    type Part2 = Option<u64>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(modules: &HashMap<String, Module>) -> usize {
+        let mut modules = modules.clone();
+
+        let mut lows = 0x0;
+        let mut highs = 0x0;
+
+        let mut work = VecDeque::new();
+        for _ in 0..1000 {
+            work.push_back(("".to_string(), "broadcaster".to_string(), 0));
+            while let Some((from, dest, pulse)) = work.pop_front() {
+                if pulse == 0 {
+                    lows += 1;
+                } else {
+                    highs += 1;
+                }
+
+                let module = modules.get_mut(&dest).unwrap();
+                module.receive(from, pulse, &mut work);
+            }
+        }
+
+        lows * highs
+    }
+
+    fn part2(modules: &HashMap<String, Module>) -> Option<u64> {
+        let mut modules = modules.clone();
+        let mut work = VecDeque::new();
+
+        let mut presses = 0x0;
+
+        // Assumption: Node before rx is a conjunction.
+        // Plan: Find lcm of the presses it takes for each input module to send a high
+        // pulse to the node before rx
+        let mut inputs_to_prev_rx = HashMap::new();
+        let prev_rx: Vec<&String> = modules.get("rx")?.inputs.keys().collect();
+        let [prev_rx] = prev_rx[..] else {
+            return None;
+        };
+        let prev_rx = &modules[prev_rx];
+        if !matches!(prev_rx.typ, ModuleType::Conjunction) {
+            return None;
+        }
+        for inp in prev_rx.inputs.iter() {
+            inputs_to_prev_rx.insert(inp.0.clone(), 0);
+        }
+        let prev_rx_name = prev_rx.name.clone();
+
+        'outer: loop {
+            presses += 1;
+            work.push_back(("".to_string(), "broadcaster".to_string(), 0));
+            while let Some((from, dest, pulse)) = work.pop_front() {
+                if let Some(val) = inputs_to_prev_rx.get_mut(&from) {
+                    if dest == prev_rx_name && pulse == 1 {
+                        *val = presses;
+                    }
+                }
+
+                if inputs_to_prev_rx.values().all(|x| *x > 0) {
+                    break 'outer Some(
+                        inputs_to_prev_rx
+                            .values()
+                            .fold(1_u64, |acc, &num| lcm(acc, num)),
+                    );
+                }
+
+                let module = modules.get_mut(&dest).unwrap();
+                module.receive(from, pulse, &mut work);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+    const EXAMPLE2: &str = include_str!("../example2.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day20::part1(&Day20::parse(EXAMPLE).unwrap()), 32000000);
+        assert_eq!(Day20::part1(&Day20::parse(EXAMPLE2).unwrap()), 11687500);
+    }
+
+    // There's no example for part 2, it needs an "rx" module.
+    #[test]
+    fn part2_without_rx() {
+        assert_eq!(Day20::part2(&Day20::parse(EXAMPLE).unwrap()), None);
+        let modules = Day20::parse("broadcaster -> a\n%a -> rx\n").unwrap();
+        assert_eq!(Day20::part2(&modules), None);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let err = Day20::parse("%a -> b\n&b -> a\n").err().unwrap();
+        assert_eq!(
+            (err.line, err.reason.as_str()),
+            (3, "expected a broadcaster module")
+        );
+        let err = Day20::parse("broadcaster -> a\nb -> a\n").err().unwrap();
+        assert_eq!(
+            (err.line, err.reason.as_str()),
+            (2, "expected %, & or broadcaster")
+        );
+        let err = Day20::parse("broadcaster -> a\n%a -> b\n&a -> b\n")
+            .err()
+            .unwrap();
+        assert_eq!(err.reason, "expected only one module named a");
+    }
+}
@@ -0,0 +1,100 @@
+use common::Solution;
+use grid::{Direction, Grid, Point};
+use parsing::ParseError;
+use std::collections::{HashSet, VecDeque};
+
+struct State {
+    pos: Point,
+    steps: usize,
+}
+
+impl State {
+    pub fn new(pos: Point, steps: usize) -> State {
+        State { pos, steps }
+    }
+}
+
+fn parse_input(input: &str) -> Result<(Grid<char>, Point), ParseError> {
+    let grid = Grid::parse_only(input, ".#S")?;
+    let start = grid
+        .position(|&c| c == 'S')
+        .ok_or_else(|| ParseError::at(input, &input[input.len()..], "expected a start 'S'"))?;
+
+    Ok((grid, start.into()))
+}
+
+// Number of garden plots that can be reached in exactly `max_steps` steps.
+// With `wrapping` the map repeats infinitely in every direction.
+fn reachable(grid: &Grid<char>, start: Point, max_steps: usize, wrapping: bool) -> usize {
+    let mut work = VecDeque::new();
+
+    work.push_back(State::new(start, 0));
+
+    let mut seen = HashSet::from([(start, 0)]);
+
+    while let Some(s) = work.pop_front() {
+        if s.steps == max_steps {
+            continue;
+        }
+        for dir in Direction::ALL {
+            let pos = s.pos.step(dir);
+            let c = match wrapping {
+                true => Some(grid.get_wrapping(pos)),
+                false => grid.get(pos),
+            };
+            if matches!(c, Some('.' | 'S')) && seen.insert((pos, s.steps + 1)) {
+                work.push_back(State::new(pos, s.steps + 1));
+            }
+        }
+    }
+
+    seen.iter().filter(|(_, steps)| *steps == max_steps).count()
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+    type Input = (Grid<char>, Point);
+    type Part1 = usize;
+    type Part2 = usize;
+
+    // Walking 26501365 steps one at a time never finishes.
+    const RUNNABLE: [bool; 2] = [true, false];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1((grid, start): &Self::Input) -> usize {
+        reachable(grid, *start, 64, false)
+    }
+
+    fn part2((grid, start): &Self::Input) -> usize {
+        reachable(grid, *start, 26501365, true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    // The examples take fewer steps than the real puzzle.
+    #[test]
+    fn part1_example() {
+        let (grid, start) = Day21::parse(EXAMPLE).unwrap();
+        assert_eq!(reachable(&grid, start, 6, false), 16);
+    }
+
+    #[test]
+    fn part2_example() {
+        let (grid, start) = Day21::parse(EXAMPLE).unwrap();
+        assert_eq!(reachable(&grid, start, 6, true), 16);
+        // These walk off the first copy of the map.
+        assert_eq!(reachable(&grid, start, 10, true), 50);
+        assert_eq!(reachable(&grid, start, 50, true), 1594);
+        assert_eq!(reachable(&grid, start, 100, true), 6536);
+    }
+}
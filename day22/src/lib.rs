@@ -0,0 +1,184 @@
+use common::Solution;
+use parsing::ParseError;
+use std::collections::HashSet;
+
+pub type Cube = (isize, isize, isize);
+pub type Brick = Vec<Cube>;
+
+fn parse_cube(input: &str, text: &str) -> Result<Cube, ParseError> {
+    let [x, y, z] = parsing::array(input, text, ",")?;
+    Ok((x, y, z))
+}
+
+fn parse_brick(input: &str, line: &str) -> Result<Brick, ParseError> {
+    let (start, end) = parsing::split_once(input, line, "~")?;
+    let (sx, sy, sz) = parse_cube(input, start)?;
+    let (ex, ey, ez) = parse_cube(input, end)?;
+
+    let mut brick = Vec::new();
+    if sx == ex && sy == ey {
+        for z in sz..=ez {
+            brick.push((sx, sy, z));
+        }
+    } else if sx == ex && sz == ez {
+        for y in sy..=ey {
+            brick.push((sx, y, sz));
+        }
+    } else if sy == ey && sz == ez {
+        for x in sx..=ex {
+            brick.push((x, sy, sz));
+        }
+    } else {
+        return Err(ParseError::at(input, line, "expected a straight brick"));
+    }
+
+    Ok(brick)
+}
+
+fn parse_input(input: &str) -> Result<(Vec<Brick>, HashSet<Cube>), ParseError> {
+    let bricks = input
+        .lines()
+        .map(|l| parse_brick(input, l))
+        .collect::<Result<_, _>>()?;
+
+    Ok(settle(bricks))
+}
+
+// Lets the bricks fall until none of them can move any further.
+fn settle(mut bricks: Vec<Brick>) -> (Vec<Brick>, HashSet<Cube>) {
+    let mut cubes = HashSet::new();
+    for brick in bricks.iter() {
+        for &cube in brick.iter() {
+            cubes.insert(cube);
+        }
+    }
+    loop {
+        let mut any_moved = false;
+        for brick in bricks.iter_mut() {
+            let mut can_move = true;
+            for &(x, y, z) in brick.iter() {
+                if z == 1 {
+                    can_move = false;
+                    break;
+                }
+
+                if cubes.contains(&(x, y, z - 1)) && !brick.contains(&(x, y, z - 1)) {
+                    can_move = false;
+                }
+            }
+
+            if can_move {
+                any_moved = true;
+                for (x, y, z) in brick.iter_mut() {
+                    assert!(cubes.contains(&(*x, *y, *z)));
+                    cubes.remove(&(*x, *y, *z));
+
+                    *z -= 1;
+
+                    cubes.insert((*x, *y, *z));
+                }
+            }
+        }
+
+        if !any_moved {
+            break;
+        }
+    }
+
+    (bricks, cubes)
+}
+
+fn remove_brick(brick_remove: &Brick, bricks: &[Brick], cubes: &HashSet<Cube>) -> usize {
+    let mut cubes = cubes.clone();
+    let mut bricks = bricks.to_vec();
+
+    for cube in brick_remove.iter() {
+        cubes.remove(cube);
+    }
+
+    let mut moved = HashSet::new();
+    loop {
+        let mut any_moved = false;
+        for (i, brick) in bricks.iter_mut().enumerate() {
+            if brick == brick_remove {
+                continue;
+            }
+            let mut can_move = true;
+            for &(x, y, z) in brick.iter() {
+                if z == 1 {
+                    can_move = false;
+                    break;
+                }
+
+                if cubes.contains(&(x, y, z - 1)) && !brick.contains(&(x, y, z - 1)) {
+                    can_move = false;
+                }
+            }
+
+            if can_move {
+                moved.insert(i);
+                any_moved = true;
+                for (x, y, z) in brick.iter_mut() {
+                    assert!(cubes.contains(&(*x, *y, *z)));
+                    cubes.remove(&(*x, *y, *z));
+
+                    *z -= 1;
+
+                    cubes.insert((*x, *y, *z));
+                }
+            }
+        }
+
+        if !any_moved {
+            break;
+        }
+    }
+
+    moved.len()
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: u8 = 22;
+    type Input = (Vec<Brick>, HashSet<Cube>);
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1((bricks, cubes): &Self::Input) -> usize {
+        let safe_bricks = bricks
+            .iter()
+            .map(|b| remove_brick(b, bricks, cubes))
+            .filter(|x| *x == 0)
+            .count();
+
+        safe_bricks
+    }
+
+    fn part2((bricks, cubes): &Self::Input) -> usize {
+        let moved_bricks: usize = bricks.iter().map(|b| remove_brick(b, bricks, cubes)).sum();
+
+        moved_bricks
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day22::part1(&Day22::parse(EXAMPLE).unwrap()), 5);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day22::part2(&Day22::parse(EXAMPLE).unwrap()), 7);
+    }
+}
@@ -0,0 +1,178 @@
+use common::Solution;
+use grid::{Direction, Grid, Point};
+use parsing::ParseError;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+// vertex -> (vertex, steps) of every vertex reachable from it
+type Graph = HashMap<Point, HashSet<(Point, usize)>>;
+
+// The start and end are the only path tiles in the first and last line.
+fn find_path(input: &str, line: &str) -> Result<usize, ParseError> {
+    line.chars()
+        .position(|x| x == '.')
+        .ok_or_else(|| ParseError::at(input, line, "expected a path tile '.'"))
+}
+
+fn parse_input(input: &str) -> Result<(Grid<char>, Point, Point), ParseError> {
+    let grid = Grid::parse_only(input, "#.><^v")?;
+    let lines: Vec<&str> = input.lines().collect();
+    let (Some(first), Some(last)) = (lines.first(), lines.last()) else {
+        return Err(ParseError::at(input, input, "expected a map"));
+    };
+    let start = find_path(input, first)?;
+    let end = find_path(input, last)?;
+
+    Ok((
+        grid,
+        Point::from((0, start)),
+        Point::from((lines.len() - 1, end)),
+    ))
+}
+
+struct State {
+    steps: usize,
+    pos: Point,
+    seen: HashSet<Point>,
+}
+
+fn compress_grid(grid: &Grid<char>, start: Point, end: Point, part2: bool) -> Graph {
+    let mut vertices = HashSet::new();
+    let mut edges = HashMap::new();
+    let slopes = HashMap::from([
+        ('>', Direction::Right),
+        ('<', Direction::Left),
+        ('^', Direction::Up),
+        ('v', Direction::Down),
+    ]);
+
+    vertices.insert(start);
+    vertices.insert(end);
+
+    for ((row, col), &c) in grid.iter() {
+        if c == '#' {
+            continue;
+        }
+        let outgoing_edges = grid
+            .neighbours4((row, col))
+            .filter(|&pos| grid[pos] != '#')
+            .count();
+        if outgoing_edges > 2 {
+            vertices.insert(Point::from((row, col)));
+        }
+    }
+
+    for &v in vertices.iter() {
+        let mut work = VecDeque::new();
+        work.push_back(State {
+            steps: 0,
+            pos: v,
+            seen: HashSet::from([v]),
+        });
+
+        while let Some(state) = work.pop_back() {
+            if vertices.contains(&state.pos) && state.pos != v {
+                edges
+                    .entry(v)
+                    .or_insert_with(HashSet::new)
+                    .insert((state.pos, state.steps));
+                continue;
+            }
+
+            let c = *grid.get(state.pos).unwrap();
+
+            assert!(c != '#');
+
+            for d in Direction::ALL {
+                let next = state.pos.step(d);
+                if grid.get(next).is_some_and(|&c| c != '#') && !state.seen.contains(&next) {
+                    if !part2 && slopes.contains_key(&c) && d != slopes[&c] {
+                        continue;
+                    }
+                    let mut seen = state.seen.clone();
+                    seen.insert(next);
+                    work.push_back(State {
+                        steps: state.steps + 1,
+                        pos: next,
+                        seen,
+                    });
+                }
+            }
+        }
+    }
+
+    edges
+}
+
+fn dfs(
+    node: &Point,
+    end: &Point,
+    graph: &Graph,
+    seen: &mut HashSet<Point>,
+    current_steps: usize,
+    max_steps: &mut usize,
+) {
+    if node == end {
+        *max_steps = (*max_steps).max(current_steps);
+        return;
+    }
+
+    if !seen.insert(*node) {
+        return;
+    }
+
+    if let Some(outgoing) = graph.get(node) {
+        for (next_node, dist) in outgoing {
+            dfs(next_node, end, graph, seen, current_steps + dist, max_steps);
+        }
+    }
+
+    seen.remove(node); // Backtrack
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+    type Input = (Grid<char>, Point, Point);
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1((grid, start, end): &Self::Input) -> usize {
+        let grid = compress_grid(grid, *start, *end, false);
+        let mut seen = HashSet::new();
+        let mut max_steps = 0x0;
+        dfs(start, end, &grid, &mut seen, 0, &mut max_steps);
+
+        max_steps
+    }
+
+    fn part2((grid, start, end): &Self::Input) -> usize {
+        let grid = compress_grid(grid, *start, *end, true);
+        let mut seen = HashSet::new();
+        let mut max_steps = 0x0;
+        dfs(start, end, &grid, &mut seen, 0, &mut max_steps);
+
+        max_steps
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day23::part1(&Day23::parse(EXAMPLE).unwrap()), 94);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day23::part2(&Day23::parse(EXAMPLE).unwrap()), 154);
+    }
+}
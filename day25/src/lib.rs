@@ -0,0 +1,132 @@
+use common::Solution;
+use parsing::ParseError;
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
+
+fn parse_input(input: &str) -> Result<HashSet<(String, String)>, ParseError> {
+    let mut ret = HashSet::new();
+    for l in input.lines() {
+        let (machine, connections) = parsing::split_once(input, l, ": ")?;
+
+        for x in connections.split_whitespace() {
+            ret.insert((machine.to_string(), x.to_string()));
+            ret.insert((x.to_string(), machine.to_string()));
+        }
+    }
+
+    Ok(ret)
+}
+
+// Adjacency lists, with every machine numbered.
+type Graph = Vec<Vec<usize>>;
+
+fn build_graph(connections: &HashSet<(String, String)>) -> Graph {
+    // numbered in name order so the groups always come out the same way round
+    let names: BTreeSet<&str> = connections.iter().map(|(a, _)| a.as_str()).collect();
+    let ids: HashMap<&str, usize> = names.into_iter().enumerate().map(|(i, n)| (n, i)).collect();
+
+    let mut graph = vec![Vec::new(); ids.len()];
+    for (a, b) in connections.iter() {
+        graph[ids[a.as_str()]].push(ids[b.as_str()]);
+    }
+
+    graph
+}
+
+fn two_groups(graph: &Graph) -> Option<(usize, usize)> {
+    let mut work = VecDeque::from([0]);
+    let mut seen = HashSet::from([0]);
+    while let Some(w) = work.pop_front() {
+        for &next in graph[w].iter() {
+            if seen.insert(next) {
+                work.push_back(next);
+            }
+        }
+    }
+
+    let not_in_seen = graph.len() - seen.len();
+
+    if not_in_seen > 0 {
+        Some((seen.len(), not_in_seen))
+    } else {
+        None
+    }
+}
+
+// The wire that the most shortest paths from every machine to every other
+// machine go through. Wires joining the two groups are used by all the paths
+// between them.
+fn busiest_wire(graph: &Graph) -> (usize, usize) {
+    let mut usage = HashMap::new();
+    for start in 0..graph.len() {
+        let mut parent = vec![None; graph.len()];
+        let mut seen = vec![false; graph.len()];
+        let mut work = VecDeque::from([start]);
+        seen[start] = true;
+        while let Some(pos) = work.pop_front() {
+            for &next in graph[pos].iter() {
+                if !seen[next] {
+                    seen[next] = true;
+                    parent[next] = Some(pos);
+                    work.push_back(next);
+                }
+            }
+        }
+
+        for end in 0..graph.len() {
+            let mut node = end;
+            while let Some(prev) = parent[node] {
+                *usage.entry((node.min(prev), node.max(prev))).or_insert(0) += 1;
+                node = prev;
+            }
+        }
+    }
+
+    usage
+        .into_iter()
+        .max_by_key(|&(_, count)| count)
+        .map(|(wire, _)| wire)
+        .unwrap()
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+    type Input = HashSet<(String, String)>;
+    /// Sizes of the two groups left after cutting three wires.
+    type Part1 = (usize, usize);
+    type Part2 = ();
+
+    fn parse(input: &str) -> Result<HashSet<(String, String)>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(connections: &HashSet<(String, String)>) -> (usize, usize) {
+        let mut graph = build_graph(connections);
+
+        // Cutting the busiest wire makes the other two stand out more, so
+        // they're found one at a time.
+        for _ in 0..3 {
+            let (a, b) = busiest_wire(&graph);
+            graph[a].retain(|&x| x != b);
+            graph[b].retain(|&x| x != a);
+        }
+
+        two_groups(&graph).expect("No three wires split the graph into two groups")
+    }
+
+    fn part2(_connections: &HashSet<(String, String)>) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn part1_example() {
+        let (a, b) = Day25::part1(&Day25::parse(EXAMPLE).unwrap());
+        assert_eq!(a * b, 54);
+    }
+}
@@ -0,0 +1,229 @@
+use common::Solution;
+use grid::Grid;
+use parsing::ParseError;
+use std::collections::{BTreeSet, HashMap};
+use std::ops::Range;
+
+fn is_symbol(c: char) -> bool {
+    !c.is_ascii_digit() && c != '.'
+}
+
+/// A number in the schematic, covering the columns in `cols` of `row`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Number {
+    pub value: u32,
+    pub row: usize,
+    pub cols: Range<usize>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Symbol {
+    pub pos: (usize, usize),
+    pub val: char,
+}
+
+impl Symbol {
+    pub fn is_gear_symbol(&self) -> bool {
+        self.val == '*'
+    }
+}
+
+/// The numbers and symbols of a schematic as a bipartite graph, with an
+/// edge between each number and every symbol next to it. Both are referred
+/// to by their index in [`Schematic::numbers`] and [`Schematic::symbols`],
+/// in reading order.
+pub struct Schematic {
+    numbers: Vec<Number>,
+    symbols: Vec<Symbol>,
+    symbols_of: Vec<Vec<usize>>,
+    numbers_of: Vec<Vec<usize>>,
+}
+
+impl Schematic {
+    pub fn parse(input: &str) -> Result<Schematic, ParseError> {
+        let grid = Grid::parse(input)?;
+        let lines: Vec<&str> = input.lines().collect();
+        let symbols: Vec<Symbol> = grid
+            .iter()
+            .filter(|(_, &c)| is_symbol(c))
+            .map(|(pos, &val)| Symbol { pos, val })
+            .collect();
+        let symbol_at: HashMap<(usize, usize), usize> =
+            (0..).zip(&symbols).map(|(i, s)| (s.pos, i)).collect();
+
+        let mut numbers = Vec::new();
+        let mut symbols_of = Vec::new();
+        let mut numbers_of = vec![Vec::new(); symbols.len()];
+        for (row, line) in grid.iter_rows().enumerate() {
+            let mut col = 0;
+            while col < line.len() {
+                let len = line[col..]
+                    .iter()
+                    .take_while(|c| c.is_ascii_digit())
+                    .count();
+                if len == 0 {
+                    col += 1;
+                    continue;
+                }
+
+                let cols = col..col + len;
+                // The rows of the grid are the lines, the digits are ASCII.
+                let start = lines[row].char_indices().nth(col).map_or(0, |(i, _)| i);
+                let digits = &lines[row][start..start + len];
+                let value = digits.parse().map_err(|_| {
+                    ParseError::at(input, digits, "expected a number that fits in a u32")
+                })?;
+                let adjacent: BTreeSet<usize> = cols
+                    .clone()
+                    .flat_map(|c| grid.neighbours8((row, c)))
+                    .filter_map(|pos| symbol_at.get(&pos).copied())
+                    .collect();
+
+                for &symbol in adjacent.iter() {
+                    numbers_of[symbol].push(numbers.len());
+                }
+                symbols_of.push(adjacent.into_iter().collect());
+                numbers.push(Number {
+                    value,
+                    row,
+                    cols: cols.clone(),
+                });
+                col = cols.end;
+            }
+        }
+
+        Ok(Schematic {
+            numbers,
+            symbols,
+            symbols_of,
+            numbers_of,
+        })
+    }
+
+    pub fn numbers(&self) -> &[Number] {
+        &self.numbers
+    }
+
+    pub fn symbols(&self) -> &[Symbol] {
+        &self.symbols
+    }
+
+    /// The symbols next to the number at index `number`.
+    pub fn symbols_next_to(&self, number: usize) -> impl Iterator<Item = &Symbol> {
+        self.symbols_of[number].iter().map(|&i| &self.symbols[i])
+    }
+
+    /// The numbers next to the symbol at index `symbol`.
+    pub fn numbers_next_to(&self, symbol: usize) -> impl Iterator<Item = &Number> {
+        self.numbers_of[symbol].iter().map(|&i| &self.numbers[i])
+    }
+
+    /// The numbers next to at least one symbol.
+    pub fn part_numbers(&self) -> impl Iterator<Item = &Number> {
+        (0..self.numbers.len())
+            .filter(|&i| !self.symbols_of[i].is_empty())
+            .map(|i| &self.numbers[i])
+    }
+
+    /// The `*` symbols next to exactly `parts` numbers, with those numbers.
+    pub fn gears(&self, parts: usize) -> impl Iterator<Item = (&Symbol, Vec<&Number>)> {
+        (0..self.symbols.len())
+            .filter(move |&i| self.symbols[i].is_gear_symbol() && self.numbers_of[i].len() == parts)
+            .map(|i| (&self.symbols[i], self.numbers_next_to(i).collect()))
+    }
+
+    /// The product of the two numbers of each gear.
+    pub fn gear_ratios(&self) -> impl Iterator<Item = u64> + '_ {
+        self.gears(2)
+            .map(|(_, numbers)| numbers.iter().map(|n| u64::from(n.value)).product())
+    }
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+    type Input = Schematic;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Schematic, ParseError> {
+        Schematic::parse(input)
+    }
+
+    fn part1(schematic: &Schematic) -> u64 {
+        schematic.part_numbers().map(|n| u64::from(n.value)).sum()
+    }
+
+    fn part2(schematic: &Schematic) -> u64 {
+        schematic.gear_ratios().sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day3::part1(&Day3::parse(EXAMPLE).unwrap()), 4361);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day3::part2(&Day3::parse(EXAMPLE).unwrap()), 467835);
+    }
+
+    #[test]
+    fn graph_queries() {
+        let schematic = Day3::parse(EXAMPLE).unwrap();
+        let numbers = schematic.numbers();
+        assert_eq!(numbers.len(), 10);
+        assert_eq!(
+            numbers[0],
+            Number {
+                value: 467,
+                row: 0,
+                cols: 0..3
+            }
+        );
+        assert_eq!(schematic.part_numbers().count(), 8);
+
+        let star: Vec<&Symbol> = schematic.symbols_next_to(0).collect();
+        assert_eq!(
+            star,
+            [&Symbol {
+                pos: (1, 3),
+                val: '*'
+            }]
+        );
+        let values: Vec<u32> = schematic.numbers_next_to(0).map(|n| n.value).collect();
+        assert_eq!(values, [467, 35]);
+
+        assert_eq!(schematic.gears(1).count(), 1);
+        assert_eq!(schematic.gear_ratios().collect::<Vec<_>>(), [16345, 451490]);
+    }
+
+    #[test]
+    fn numbers_next_to_several_symbols() {
+        let schematic = Day3::parse("*..\n.12\n..#\n4.*\n").unwrap();
+        let symbols: Vec<char> = schematic.symbols_next_to(0).map(|s| s.val).collect();
+        assert_eq!(symbols, ['*', '#']);
+        let values: Vec<u32> = schematic.numbers_next_to(2).map(|n| n.value).collect();
+        assert_eq!(values, [] as [u32; 0]);
+        assert_eq!(schematic.numbers_next_to(1).count(), 1);
+        assert_eq!(schematic.gears(0).count(), 1);
+    }
+
+    #[test]
+    fn big_numbers() {
+        let err = Day3::parse("..12345678901\n").err().unwrap();
+        assert_eq!((err.column, err.text.as_str()), (3, "12345678901"));
+        assert_eq!(err.reason, "expected a number that fits in a u32");
+
+        let schematic = Day3::parse("99999*99999\n").unwrap();
+        assert_eq!(Day3::part2(&schematic), 9999800001);
+    }
+}
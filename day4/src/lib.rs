@@ -0,0 +1,206 @@
+use common::Solution;
+use parsing::{ParseError, Record};
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Card {
+    number: usize,
+    matching: Vec<usize>,
+}
+
+impl Card {
+    pub fn new(number: usize, matching: Vec<usize>) -> Card {
+        Card { number, matching }
+    }
+
+    pub fn number(&self) -> usize {
+        self.number
+    }
+
+    /// The winning numbers the card has.
+    pub fn matching(&self) -> &[usize] {
+        &self.matching
+    }
+}
+
+// Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+impl Record for Card {
+    fn parse(input: &str, line: &str) -> Result<Card, ParseError> {
+        let (card, numbers) = parsing::split_once(input, line, ":")?;
+        let card_num = parsing::number(input, parsing::prefixed(input, card, "Card")?)?;
+        let (winning, i_have) = parsing::split_once(input, numbers, "|")?;
+
+        let winning: Vec<usize> = parsing::numbers(input, winning)?;
+        let i_have: Vec<usize> = parsing::numbers(input, i_have)?;
+
+        let matching = i_have
+            .iter()
+            .filter(|&x| winning.contains(x))
+            .copied()
+            .collect();
+
+        Ok(Card::new(card_num, matching))
+    }
+}
+
+/// The puzzle's scoring: a point for the first match, doubled for every
+/// match after that.
+pub fn doubling(matches: usize) -> u32 {
+    match matches {
+        0 => 0,
+        n => 1 << (n - 1),
+    }
+}
+
+/// A card that wins copies of cards past the last one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Overrun {
+    pub card: usize,
+    pub up_to: usize,
+    pub last: usize,
+}
+
+impl fmt::Display for Overrun {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "card {} wins copies up to card {}, but the last card is {}",
+            self.card, self.up_to, self.last
+        )
+    }
+}
+
+/// A pile of cards, where a card with `n` matches wins a copy of each of
+/// the `n` cards after it in the pile.
+pub struct Scratchcards {
+    cards: Vec<Card>,
+}
+
+impl Scratchcards {
+    pub fn new(cards: Vec<Card>) -> Scratchcards {
+        Scratchcards { cards }
+    }
+
+    pub fn cards(&self) -> &[Card] {
+        &self.cards
+    }
+
+    /// The points of every card under `scoring`, which gets the number of
+    /// matches.
+    pub fn points(&self, scoring: impl Fn(usize) -> u32) -> Vec<u32> {
+        self.cards
+            .iter()
+            .map(|c| scoring(c.matching.len()))
+            .collect()
+    }
+
+    /// How many of each card there are in the end, originals included.
+    /// Copies of cards past the last one are dropped.
+    pub fn copies(&self) -> Vec<usize> {
+        let n = self.cards.len();
+        let mut copies = Vec::with_capacity(n);
+        // What each card adds to the cards after it stops counting at the
+        // end of its wins, `running` is the sum of what still counts.
+        let mut expiring = vec![0; n + 1];
+        let mut running = 0;
+
+        for (i, card) in self.cards.iter().enumerate() {
+            running -= expiring[i];
+            let count = 1 + running;
+            copies.push(count);
+
+            let end = (i + 1 + card.matching.len()).min(n);
+            if end > i + 1 {
+                running += count;
+                expiring[end] += count;
+            }
+        }
+
+        copies
+    }
+
+    /// Like [`Scratchcards::copies`], but a card winning copies past the
+    /// last card is an error.
+    pub fn copies_checked(&self) -> Result<Vec<usize>, Overrun> {
+        let n = self.cards.len();
+        for (i, card) in self.cards.iter().enumerate() {
+            if !card.matching.is_empty() && i + card.matching.len() >= n {
+                return Err(Overrun {
+                    card: card.number,
+                    up_to: card.number + card.matching.len(),
+                    last: self.cards[n - 1].number,
+                });
+            }
+        }
+
+        Ok(self.copies())
+    }
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+    type Input = Scratchcards;
+    type Part1 = u32;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Scratchcards, ParseError> {
+        Ok(Scratchcards::new(parsing::records(input, input)?))
+    }
+
+    fn part1(cards: &Scratchcards) -> u32 {
+        cards.points(doubling).iter().sum()
+    }
+
+    fn part2(cards: &Scratchcards) -> usize {
+        cards.copies().iter().sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day4::part1(&Day4::parse(EXAMPLE).unwrap()), 13);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day4::part2(&Day4::parse(EXAMPLE).unwrap()), 30);
+    }
+
+    #[test]
+    fn copies_and_points() {
+        let cards = Day4::parse(EXAMPLE).unwrap();
+        assert_eq!(cards.copies(), [1, 2, 4, 8, 14, 1]);
+        assert_eq!(cards.copies_checked(), Ok(cards.copies()));
+        assert_eq!(cards.points(doubling), [8, 2, 2, 1, 0, 0]);
+        assert_eq!(cards.points(|matches| matches as u32), [4, 2, 2, 1, 0, 0]);
+    }
+
+    #[test]
+    fn wins_past_the_last_card() {
+        let input = "Card 1: 1 2 | 1 2\nCard 2: 3 4 5 | 3 4 5\nCard 3: 6 | 7\n";
+        let cards = Day4::parse(input).unwrap();
+        assert_eq!(cards.copies(), [1, 2, 4]);
+
+        let err = cards.copies_checked().unwrap_err();
+        assert_eq!(
+            err,
+            Overrun {
+                card: 2,
+                up_to: 5,
+                last: 3
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "card 2 wins copies up to card 5, but the last card is 3"
+        );
+    }
+}
@@ -0,0 +1,343 @@
+use common::Solution;
+use intervals::IntervalSet;
+use itertools::Itertools;
+use parsing::{ParseError, Record};
+use std::ops::Range;
+
+mod piecewise;
+
+pub use piecewise::{Piecewise, Segment};
+
+pub struct Map {
+    mappings: Vec<Mapping>,
+}
+
+impl Map {
+    pub fn new(mappings: Vec<Mapping>) -> Map {
+        Map { mappings }
+    }
+
+    /// Where `source` ends up. Values no mapping covers keep their number.
+    pub fn map(&self, source: &IntervalSet<usize>) -> IntervalSet<usize> {
+        let mut unmapped = source.clone();
+        let mut mapped = Vec::new();
+        for mapping in self.mappings.iter() {
+            let (moved, rest) = mapping.try_map(&unmapped);
+            mapped.extend_from_slice(moved.ranges());
+            unmapped = rest;
+        }
+
+        IntervalSet::from_ranges(mapped).union(&unmapped)
+    }
+
+    /// Every value that [`Map::map`] sends into `dest`.
+    pub fn preimage(&self, dest: &IntervalSet<usize>) -> IntervalSet<usize> {
+        let mut covered = IntervalSet::new();
+        let mut sources = IntervalSet::new();
+        for mapping in self.mappings.iter() {
+            // A value an earlier mapping covers was moved by that one.
+            sources = sources.union(&mapping.unmap(dest).difference(&covered));
+            covered.insert(mapping.source.clone());
+        }
+
+        sources.union(&dest.difference(&covered))
+    }
+}
+
+/// Moves the values in `source` to the same place in `dest`.
+pub struct Mapping {
+    source: Range<usize>,
+    dest: usize,
+}
+
+impl Mapping {
+    pub fn new(source: Range<usize>, dest: usize) -> Mapping {
+        Mapping { source, dest }
+    }
+
+    /// Splits `values` into what this mapping moves, already moved, and
+    /// what it doesn't cover.
+    pub fn try_map(&self, values: &IntervalSet<usize>) -> (IntervalSet<usize>, IntervalSet<usize>) {
+        let source = IntervalSet::from(self.source.clone());
+        let moved = values
+            .intersection(&source)
+            .ranges()
+            .iter()
+            .map(|r| r.start - self.source.start + self.dest..r.end - self.source.start + self.dest)
+            .collect();
+
+        (moved, values.difference(&source))
+    }
+
+    /// The part of `source` this mapping moves into `values`.
+    pub fn unmap(&self, values: &IntervalSet<usize>) -> IntervalSet<usize> {
+        let dest = self.dest..self.dest + self.source.len();
+        values
+            .intersection(&IntervalSet::from(dest))
+            .ranges()
+            .iter()
+            .map(|r| r.start - self.dest + self.source.start..r.end - self.dest + self.source.start)
+            .collect()
+    }
+}
+
+// 50 98 2
+impl Record for Mapping {
+    fn parse(input: &str, line: &str) -> Result<Mapping, ParseError> {
+        let [dest, src, len] = parsing::numbers(input, line)?[..] else {
+            return Err(ParseError::at(input, line, "expected three numbers"));
+        };
+
+        Ok(Mapping::new(src..src + len, dest))
+    }
+}
+
+fn seeds_part1(seeds: &[usize]) -> IntervalSet<usize> {
+    seeds.iter().map(|&x| x..x + 1).collect()
+}
+
+fn seeds_part2(seeds: &[usize]) -> IntervalSet<usize> {
+    seeds
+        .iter()
+        .tuples()
+        .map(|(&start, &len)| start..start + len)
+        .collect()
+}
+
+// seed-to-soil map:
+// 50 98 2
+// ...
+fn parse_map(input: &str, block: &str) -> Result<Map, ParseError> {
+    let (header, mappings) = block.split_once('\n').unwrap_or((block, ""));
+    if !header.ends_with("map:") {
+        return Err(ParseError::at(input, header, "expected a map header"));
+    }
+
+    Ok(Map::new(parsing::records(input, mappings)?))
+}
+
+pub struct Almanac {
+    seeds: Vec<usize>,
+    maps: Vec<Map>,
+}
+
+impl Almanac {
+    /// The seeds as part 1 reads them, one number each.
+    pub fn seed_values(&self) -> IntervalSet<usize> {
+        seeds_part1(&self.seeds)
+    }
+
+    /// The seeds as part 2 reads them, pairs of start and length.
+    pub fn seed_ranges(&self) -> IntervalSet<usize> {
+        seeds_part2(&self.seeds)
+    }
+
+    /// Every seed that ends up in `locations`.
+    pub fn seeds_for(&self, locations: &IntervalSet<usize>) -> IntervalSet<usize> {
+        self.maps
+            .iter()
+            .rev()
+            .fold(locations.clone(), |values, map| map.preimage(&values))
+    }
+
+    /// The lowest location any of `seeds` reaches, with the seed, found by
+    /// looking back from ever larger location ranges.
+    pub fn search_lowest(&self, seeds: &IntervalSet<usize>) -> Option<(usize, usize)> {
+        let reaches = |end: usize| {
+            !self
+                .seeds_for(&(0..end).into())
+                .intersection(seeds)
+                .is_empty()
+        };
+
+        let mut end = 1;
+        while !reaches(end) {
+            if end == usize::MAX {
+                return None;
+            }
+            end = end.saturating_mul(2);
+        }
+
+        // `reaches(end)` holds and `reaches(lo)` doesn't.
+        let mut lo = end / 2;
+        while lo + 1 < end {
+            let mid = lo + (end - lo) / 2;
+            match reaches(mid) {
+                true => end = mid,
+                false => lo = mid,
+            }
+        }
+
+        let location = end - 1;
+        let seed = self
+            .seeds_for(&(location..end).into())
+            .intersection(seeds)
+            .min()?;
+        Some((seed, location))
+    }
+
+    /// Every map in one, from seed to location.
+    pub fn composed(&self) -> Piecewise {
+        Piecewise::compose(&self.maps)
+    }
+}
+
+// `None` if there are no seeds.
+fn find_lowest_loc_num(seeds: IntervalSet<usize>, almanac: &Almanac) -> Option<usize> {
+    let (_, location) = almanac.composed().lowest(&seeds)?;
+    Some(location)
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+    type Input = Almanac;
+    type Part1 = Option<usize>;
+    type Part2 = Option<usize>;
+
+    fn parse(input: &str) -> Result<Almanac, ParseError> {
+        let mut blocks = parsing::blocks(input);
+        let seeds = blocks.next().unwrap_or(input);
+        let seeds = parsing::numbers(input, parsing::prefixed(input, seeds, "seeds:")?)?;
+        let maps = blocks
+            .map(|block| parse_map(input, block))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Almanac { seeds, maps })
+    }
+
+    fn part1(almanac: &Almanac) -> Option<usize> {
+        find_lowest_loc_num(almanac.seed_values(), almanac)
+    }
+
+    fn part2(almanac: &Almanac) -> Option<usize> {
+        find_lowest_loc_num(almanac.seed_ranges(), almanac)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day5::part1(&Day5::parse(EXAMPLE).unwrap()), Some(35));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day5::part2(&Day5::parse(EXAMPLE).unwrap()), Some(46));
+    }
+
+    #[test]
+    fn no_seeds() {
+        let almanac = Day5::parse("seeds:\n\nseed-to-soil map:\n50 98 2\n").unwrap();
+        assert_eq!((Day5::part1(&almanac), Day5::part2(&almanac)), (None, None));
+        let almanac = Day5::parse("seeds: 79 0\n").unwrap();
+        assert_eq!(
+            (Day5::part1(&almanac), Day5::part2(&almanac)),
+            (Some(0), None)
+        );
+    }
+
+    #[test]
+    fn try_map() {
+        let mapping = Mapping::new(3..5, 100);
+
+        let (moved, rest) = mapping.try_map(&IntervalSet::from(0..10));
+        assert_eq!(moved, IntervalSet::from(100..102));
+        assert_eq!(rest.ranges(), [0..3, 5..10]);
+
+        let (moved, rest) = mapping.try_map(&IntervalSet::from(0..4));
+        assert_eq!(moved, IntervalSet::from(100..101));
+        assert_eq!(rest, IntervalSet::from(0..3));
+
+        let (moved, rest) = mapping.try_map(&IntervalSet::from(4..12));
+        assert_eq!(moved, IntervalSet::from(101..102));
+        assert_eq!(rest, IntervalSet::from(5..12));
+
+        let (moved, rest) = mapping.try_map(&IntervalSet::from(5..8));
+        assert!(moved.is_empty());
+        assert_eq!(rest, IntervalSet::from(5..8));
+    }
+
+    #[test]
+    fn adjacent_results_are_merged() {
+        let map = Map::new(vec![Mapping::new(0..5, 10), Mapping::new(5..10, 15)]);
+        let mapped = map.map(&IntervalSet::from_ranges([0..3, 3..10, 20..25]));
+        assert_eq!(mapped, IntervalSet::from(10..25));
+    }
+
+    #[test]
+    fn preimage() {
+        let map = Map::new(vec![Mapping::new(0..10, 100), Mapping::new(5..15, 200)]);
+        let sources = map.preimage(&IntervalSet::from_ranges([102..108, 203..210, 0..20]));
+        assert_eq!(sources.ranges(), [2..8, 10..20, 102..108, 203..210]);
+        assert!(map.preimage(&IntervalSet::from(0..5)).is_empty());
+        let sources = map.preimage(&IntervalSet::from(205..206));
+        assert_eq!(sources.ranges(), [10..11, 205..206]);
+    }
+
+    #[test]
+    fn seeds_for_locations() {
+        let almanac = Day5::parse(EXAMPLE).unwrap();
+        let below_50 = almanac.seeds_for(&IntervalSet::from(0..50));
+        let composed = almanac.composed();
+        for seed in 0..200 {
+            assert_eq!(below_50.contains(seed), composed.apply(seed) < 50);
+        }
+
+        for seeds in [almanac.seed_values(), almanac.seed_ranges()] {
+            assert_eq!(almanac.search_lowest(&seeds), composed.lowest(&seeds));
+        }
+        assert_eq!(almanac.search_lowest(&IntervalSet::new()), None);
+    }
+
+    #[test]
+    fn composed_maps() {
+        let almanac = Day5::parse(EXAMPLE).unwrap();
+        let composed = almanac.composed();
+        let segments = composed.segments();
+        assert_eq!(segments.first().unwrap().range.start, 0);
+        assert_eq!(segments.last().unwrap().range.end, usize::MAX);
+        assert!(segments
+            .windows(2)
+            .all(|w| w[0].range.end == w[1].range.start));
+        assert!(segments.windows(2).all(|w| w[0].offset != w[1].offset));
+
+        for seeds in [almanac.seed_values(), almanac.seed_ranges()] {
+            let folded = almanac
+                .maps
+                .iter()
+                .fold(seeds.clone(), |v, map| map.map(&v));
+            assert_eq!(composed.apply_set(&seeds), folded);
+        }
+        let locations: Vec<usize> = [79, 14, 55, 13].map(|s| composed.apply(s)).into();
+        assert_eq!(locations, [82, 43, 86, 35]);
+
+        let seeds = almanac.seed_ranges();
+        let candidates = composed.min_candidates(&seeds);
+        assert!(candidates.iter().all(|&seed| seeds.contains(seed)));
+        assert_eq!(composed.lowest(&seeds), Some((82, 46)));
+    }
+
+    #[test]
+    fn overlapping_mappings() {
+        let map = Map::new(vec![Mapping::new(0..10, 100), Mapping::new(5..15, 200)]);
+        let f = Piecewise::from_map(&map);
+        assert_eq!(
+            [4, 9, 10, 14, 15].map(|x| f.apply(x)),
+            [104, 109, 205, 209, 15]
+        );
+
+        let g = f.then(&Piecewise::from_map(&Map::new(vec![Mapping::new(
+            100..110,
+            0,
+        )])));
+        assert_eq!([3, 12, 104].map(|x| g.apply(x)), [3, 207, 4]);
+        assert_eq!(g.segments().len(), 5);
+    }
+}
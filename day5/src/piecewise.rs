@@ -0,0 +1,178 @@
+use crate::Map;
+use intervals::IntervalSet;
+use std::collections::BTreeSet;
+use std::fmt;
+use std::ops::Range;
+
+/// The values in `range` move by `offset`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Segment {
+    pub range: Range<usize>,
+    pub offset: isize,
+}
+
+fn shift(range: &Range<usize>, offset: isize) -> Range<usize> {
+    let move_by = |x: usize| {
+        x.checked_add_signed(offset)
+            .expect("mapped values fit in a usize")
+    };
+    move_by(range.start)..move_by(range.end)
+}
+
+/// A function on all of `0..usize::MAX`, as sorted segments that follow
+/// each other without gaps. Neighbours never have the same offset.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Piecewise {
+    segments: Vec<Segment>,
+}
+
+impl Piecewise {
+    pub fn identity() -> Piecewise {
+        Piecewise {
+            segments: vec![Segment {
+                range: 0..usize::MAX,
+                offset: 0,
+            }],
+        }
+    }
+
+    // Joins neighbours with the same offset.
+    fn merged(segments: Vec<Segment>) -> Piecewise {
+        let mut merged: Vec<Segment> = Vec::with_capacity(segments.len());
+        for segment in segments {
+            match merged.last_mut() {
+                Some(last) if last.offset == segment.offset => last.range.end = segment.range.end,
+                _ => merged.push(segment),
+            }
+        }
+
+        Piecewise { segments: merged }
+    }
+
+    /// The same function as [`Map::map`]: where mappings overlap the first
+    /// one wins, values no mapping covers stay put.
+    pub fn from_map(map: &Map) -> Piecewise {
+        let mut bounds: BTreeSet<usize> = [0, usize::MAX].into();
+        for mapping in map.mappings.iter() {
+            bounds.extend([mapping.source.start, mapping.source.end]);
+        }
+
+        let bounds: Vec<usize> = bounds.into_iter().collect();
+        let segments = bounds
+            .windows(2)
+            .map(|w| {
+                let offset = map
+                    .mappings
+                    .iter()
+                    .find(|m| m.source.start <= w[0] && w[1] <= m.source.end)
+                    .map_or(0, |m| m.dest as isize - m.source.start as isize);
+                Segment {
+                    range: w[0]..w[1],
+                    offset,
+                }
+            })
+            .collect();
+
+        Piecewise::merged(segments)
+    }
+
+    /// All of `maps`, one after the other.
+    pub fn compose(maps: &[Map]) -> Piecewise {
+        maps.iter().fold(Piecewise::identity(), |f, map| {
+            f.then(&Piecewise::from_map(map))
+        })
+    }
+
+    /// `self` followed by `next`.
+    pub fn then(&self, next: &Piecewise) -> Piecewise {
+        let mut segments = Vec::new();
+        for segment in self.segments.iter() {
+            let image = shift(&segment.range, segment.offset);
+            for piece in next.segments[next.index(image.start)..].iter() {
+                if piece.range.start >= image.end {
+                    break;
+                }
+
+                let start = image.start.max(piece.range.start);
+                let end = image.end.min(piece.range.end);
+                segments.push(Segment {
+                    range: shift(&(start..end), -segment.offset),
+                    offset: segment.offset + piece.offset,
+                });
+            }
+        }
+
+        Piecewise::merged(segments)
+    }
+
+    pub fn segments(&self) -> &[Segment] {
+        &self.segments
+    }
+
+    // The segment holding `value`.
+    fn index(&self, value: usize) -> usize {
+        self.segments.partition_point(|s| s.range.end <= value)
+    }
+
+    pub fn apply(&self, value: usize) -> usize {
+        let segment = &self.segments[self.index(value)];
+        shift(&(value..value), segment.offset).start
+    }
+
+    /// The values `values` end up as.
+    pub fn apply_set(&self, values: &IntervalSet<usize>) -> IntervalSet<usize> {
+        self.pieces(values)
+            .map(|(range, offset)| shift(&range, offset))
+            .collect()
+    }
+
+    // The parts of `values` in each segment, with the segment's offset.
+    fn pieces<'a>(
+        &'a self,
+        values: &'a IntervalSet<usize>,
+    ) -> impl Iterator<Item = (Range<usize>, isize)> + 'a {
+        values.ranges().iter().flat_map(move |range| {
+            self.segments[self.index(range.start)..]
+                .iter()
+                .take_while(move |s| s.range.start < range.end)
+                .map(move |s| {
+                    let start = range.start.max(s.range.start);
+                    let end = range.end.min(s.range.end);
+                    (start..end, s.offset)
+                })
+        })
+    }
+
+    /// The values of `values` where the function could be smallest: as it
+    /// only adds offsets, the start of each piece a segment cuts out.
+    pub fn min_candidates(&self, values: &IntervalSet<usize>) -> Vec<usize> {
+        self.pieces(values).map(|(range, _)| range.start).collect()
+    }
+
+    /// The value of `values` that ends up smallest, with where it ends up.
+    pub fn lowest(&self, values: &IntervalSet<usize>) -> Option<(usize, usize)> {
+        self.min_candidates(values)
+            .into_iter()
+            .map(|value| (value, self.apply(value)))
+            .min_by_key(|&(_, result)| result)
+    }
+}
+
+impl fmt::Display for Piecewise {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{:>12}  {:>12}  {:>12}", "from", "to", "offset")?;
+        for segment in self.segments.iter() {
+            let end = match segment.range.end {
+                usize::MAX => "-".to_string(),
+                end => end.to_string(),
+            };
+            writeln!(
+                f,
+                "{:>12}  {:>12}  {:>+12}",
+                segment.range.start, end, segment.offset
+            )?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,328 @@
+use common::Solution;
+use parsing::ParseError;
+use std::iter::zip;
+use std::ops::RangeInclusive;
+
+mod physics;
+
+pub use physics::Physics;
+
+#[derive(Debug)]
+pub struct Race {
+    time: u128,   // millisecs
+    record: u128, // millimeter
+}
+
+impl Race {
+    pub fn new(time: u128, record: u128) -> Race {
+        Race { time, record }
+    }
+
+    pub fn time(&self) -> u128 {
+        self.time
+    }
+
+    pub fn record(&self) -> u128 {
+        self.record
+    }
+
+    /// With the puzzle's physics, exactly.
+    pub fn beaten_by(&self, hold_time: u128) -> bool {
+        // A distance too large for a u128 beats any record.
+        hold_time
+            .checked_mul(self.time - hold_time)
+            .is_none_or(|distance| distance > self.record)
+    }
+
+    fn beaten_with(&self, physics: &Physics, hold_time: u128) -> bool {
+        Boat::with_physics(hold_time, self.time, *physics).get_final_distance() > self.record as f64
+    }
+
+    /// The hold times that beat the record, `h * (time - h) > record`. Empty
+    /// if none do.
+    pub fn winning_holds(&self) -> RangeInclusive<u128> {
+        // The distance is symmetric around the middle, where it's largest.
+        let (mid, odd) = (self.time / 2, self.time % 2);
+        if !self.beaten_by(mid) {
+            return RangeInclusive::new(1, 0);
+        }
+
+        // Holding `mid - k` goes `k * k + k * odd` below the best distance,
+        // so the shortest winning hold is about `mid - sqrt(best - record)`.
+        let mut shortest = match mid.checked_mul(mid + odd) {
+            Some(best) => mid - (best - self.record).isqrt().min(mid),
+            None => first_hold(0, mid, |hold| self.beaten_by(hold)),
+        };
+        // The square root is rounded down and ignores the `k * odd` term.
+        while !self.beaten_by(shortest) {
+            shortest += 1;
+        }
+        while shortest > 0 && self.beaten_by(shortest - 1) {
+            shortest -= 1;
+        }
+
+        shortest..=self.time - shortest
+    }
+
+    /// Like [`Race::winning_holds`] for any physics. Holding longer means
+    /// more speed but less time to use it, so the distance rises to a single
+    /// peak and falls again: find the peak, then bisect either side of it
+    /// for where the distance crosses the record. Distances are f64s, so for
+    /// races longer than 2^53 ms this is only as exact as they are.
+    pub fn winning_holds_with(&self, physics: &Physics) -> RangeInclusive<u128> {
+        if *physics == Physics::default() {
+            return self.winning_holds();
+        }
+
+        let distance = |hold| Boat::with_physics(hold, self.time, *physics).get_final_distance();
+        let peak = first_hold(0, self.time, |hold| distance(hold + 1) <= distance(hold));
+        if !self.beaten_with(physics, peak) {
+            return RangeInclusive::new(1, 0);
+        }
+
+        let shortest = first_hold(0, peak, |hold| self.beaten_with(physics, hold));
+        let longest = first_hold(peak, self.time, |hold| !self.beaten_with(physics, hold + 1));
+        shortest..=longest
+    }
+
+    pub fn ways_to_win(&self) -> u128 {
+        count(&self.winning_holds())
+    }
+
+    pub fn ways_to_win_with(&self, physics: &Physics) -> u128 {
+        count(&self.winning_holds_with(physics))
+    }
+}
+
+fn count(holds: &RangeInclusive<u128>) -> u128 {
+    match holds.is_empty() {
+        true => 0,
+        false => holds.end() - holds.start() + 1,
+    }
+}
+
+// The first hold time in `lo..hi` where `pred` holds, `hi` if none does.
+// `pred` has to be false up to some point and true after it.
+fn first_hold(mut lo: u128, mut hi: u128, pred: impl Fn(u128) -> bool) -> u128 {
+    while lo < hi {
+        let hold = lo + (hi - lo) / 2;
+        match pred(hold) {
+            true => hi = hold,
+            false => lo = hold + 1,
+        }
+    }
+
+    lo
+}
+
+pub struct Boat {
+    hold_time: u128,
+    race_length: u128,
+    physics: Physics,
+}
+
+impl Boat {
+    pub fn new(hold_time: u128, race_length: u128) -> Boat {
+        Boat::with_physics(hold_time, race_length, Physics::default())
+    }
+
+    pub fn with_physics(hold_time: u128, race_length: u128, physics: Physics) -> Boat {
+        Boat {
+            hold_time,
+            race_length,
+            physics,
+        }
+    }
+
+    pub fn get_final_distance(&self) -> f64 {
+        let speed = self.physics.speed(self.hold_time as f64);
+        let time_left = self.race_length - self.hold_time;
+
+        self.physics.distance(speed, time_left as f64)
+    }
+}
+
+fn parse_numbers(input: &str, line: &str, label: &str) -> Result<Vec<u128>, ParseError> {
+    parsing::numbers(input, parsing::prefixed(input, line, label)?)
+}
+
+/// The races on the sheet, and the one long race part 2 reads it as.
+pub struct Races {
+    races: Vec<Race>,
+    merged: Race,
+}
+
+impl Races {
+    pub fn races(&self) -> &[Race] {
+        &self.races
+    }
+
+    pub fn merged(&self) -> &Race {
+        &self.merged
+    }
+}
+
+fn parse_records(input: &str) -> Result<Races, ParseError> {
+    let mut lines = input.lines();
+    let end = &input[input.len()..];
+    let time_line = lines.next().unwrap_or(end);
+    let times = parse_numbers(input, time_line, "Time:")?;
+    let distance_line = lines.next().unwrap_or(end);
+    let distances = parse_numbers(input, distance_line, "Distance:")?;
+
+    if times.len() != distances.len() {
+        let reason = format!("expected {} distances", times.len());
+        return Err(ParseError::at(input, distance_line, reason));
+    }
+
+    // part 2 reads the numbers on each line as one number, ignoring the spaces
+    let merged = Race::new(
+        merge(input, time_line, &times)?,
+        merge(input, distance_line, &distances)?,
+    );
+    let races = zip(times, distances)
+        .map(|(t, d)| Race::new(t, d))
+        .collect();
+
+    Ok(Races { races, merged })
+}
+
+fn merge(input: &str, line: &str, numbers: &[u128]) -> Result<u128, ParseError> {
+    let digits: String = numbers.iter().map(|n| n.to_string()).collect();
+    digits.parse().map_err(|_| {
+        ParseError::at(
+            input,
+            line,
+            "expected the numbers together to fit in a u128",
+        )
+    })
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+    type Input = Races;
+    // `None` if the product doesn't fit in a u128.
+    type Part1 = Option<u128>;
+    type Part2 = u128;
+
+    fn parse(input: &str) -> Result<Races, ParseError> {
+        parse_records(input)
+    }
+
+    fn part1(races: &Races) -> Option<u128> {
+        races.races.iter().try_fold(1u128, |product, race| {
+            product.checked_mul(race.ways_to_win())
+        })
+    }
+
+    fn part2(races: &Races) -> u128 {
+        races.merged.ways_to_win()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day6::part1(&Day6::parse(EXAMPLE).unwrap()), Some(288));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day6::part2(&Day6::parse(EXAMPLE).unwrap()), 71503);
+    }
+
+    #[test]
+    fn winning_holds_match_trying_every_hold() {
+        for time in 0..40 {
+            for record in 0..(time * time / 4 + 2) {
+                let race = Race::new(time, record);
+                let wins: Vec<u128> = (0..=time).filter(|&h| race.beaten_by(h)).collect();
+                let holds = race.winning_holds();
+                assert_eq!(holds.clone().collect::<Vec<_>>(), wins, "{:?}", race);
+                assert_eq!(race.ways_to_win(), wins.len() as u128);
+            }
+        }
+    }
+
+    #[test]
+    fn other_physics() {
+        let race = Race::new(30, 200);
+        let puzzle = Physics::default();
+        assert_eq!(race.winning_holds_with(&puzzle), 11..=19);
+
+        let faster = Physics {
+            acceleration: 2.0,
+            ..puzzle
+        };
+        let capped = Physics {
+            max_speed: Some(12.0),
+            ..puzzle
+        };
+        let dragged = Physics {
+            drag: 0.05,
+            ..puzzle
+        };
+        for physics in [faster, capped, dragged] {
+            let wins: Vec<u128> = (0..=30)
+                .filter(|&h| Boat::with_physics(h, 30, physics).get_final_distance() > 200.0)
+                .collect();
+            let holds = race.winning_holds_with(&physics);
+            assert_eq!(holds.collect::<Vec<_>>(), wins, "{:?}", physics);
+        }
+        assert_eq!(race.winning_holds_with(&faster), 4..=26);
+        assert_eq!(race.winning_holds_with(&capped), 11..=13);
+
+        let stuck = Physics {
+            max_speed: Some(5.0),
+            ..puzzle
+        };
+        assert_eq!(race.ways_to_win_with(&stuck), 0);
+    }
+
+    #[test]
+    fn huge_inputs() {
+        let races = Day6::parse(&format!("Time: {}\nDistance: 1\n", u128::MAX)).unwrap();
+        assert_eq!(Day6::part1(&races), Some(u128::MAX - 1));
+        assert_eq!(Day6::part2(&races), u128::MAX - 1);
+
+        let time = 1u128 << 100;
+        let races = Day6::parse(&format!("Time: {} {}\nDistance: 1 1\n", time, time));
+        let err = races.err().unwrap();
+        assert_eq!(err.reason, "expected the numbers together to fit in a u128");
+        assert_eq!(err.line, 1);
+
+        let err = Day6::parse("Time: 7 15 30\nDistance: 9 4O 200\n")
+            .err()
+            .unwrap();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 13, "4O"));
+
+        let races = Races {
+            races: vec![Race::new(time, 1), Race::new(time, 1)],
+            merged: Race::new(0, 0),
+        };
+        assert_eq!(Day6::part1(&races), None);
+    }
+
+    #[test]
+    fn huge_races() {
+        let race = Race::new(71530, 940200);
+        assert_eq!(race.winning_holds(), 14..=71516);
+
+        let race = Race::new(1 << 64, 1);
+        assert_eq!(race.winning_holds(), 1..=(1 << 64) - 1);
+        let race = Race::new(1 << 64, (1 << 126) - 1);
+        assert_eq!(race.winning_holds(), 1 << 63..=1 << 63);
+
+        let race = Race::new(u128::MAX, 1 << 100);
+        let holds = race.winning_holds();
+        assert!(!race.beaten_by(holds.start() - 1) && race.beaten_by(*holds.start()));
+        assert!(race.beaten_by(*holds.end()) && !race.beaten_by(holds.end() + 1));
+    }
+}
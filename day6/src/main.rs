@@ -1,110 +1,64 @@
-use core::num;
-use regex::Regex;
-use std::iter::zip;
-use std::str;
-
-#[derive(Debug)]
-struct Race {
-    time: usize,   // millisecs
-    record: usize, // millimeter
-}
-
-impl Race {
-    pub fn new(time: usize, record: usize) -> Race {
-        Race { time, record }
-    }
-}
-
-struct Boat {
-    hold_time: usize,
-    race_length: usize,
-}
-
-impl Boat {
-    pub fn new(hold_time: usize, race_length: usize) -> Boat {
-        Boat {
-            hold_time,
-            race_length,
-        }
-    }
-
-    pub fn get_final_distance(&self) -> usize {
-        let speed = self.hold_time;
-        let time_left = self.race_length - speed;
-
-        speed * time_left
-    }
+use common::solution::{self, Modes, Options};
+use day6::{Day6, Physics, Race};
+
+const INPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+const USAGE: &str = "\
+usage: day6 [<input file>|-] [--format text|json]
+       day6 --physics <settings> [<input file>|-]
+
+--physics races the boats with other settings, e.g.
+acceleration=2,max_speed=30,drag=0.01, and shows the hold times that win
+each race. Settings left out keep the puzzle's: acceleration=1, no
+max_speed and drag=0.";
+const MODES: Modes = Modes {
+    flags: &["--physics"],
+    with_value: &["--physics"],
+    usage: USAGE,
+};
+
+fn parse_physics_args(options: &Options) -> Result<Physics, String> {
+    let settings = options.value("--physics").ok_or("missing --physics")?;
+    Physics::parse(settings).map_err(|err| err.to_string())
 }
 
-fn parse_records(input: &str, part2: bool) -> Vec<Race> {
-    let lines: Vec<&str> = input.lines().collect();
-    let num_re = Regex::new(r"\d+").unwrap();
-    let times: Vec<usize> = num_re
-        .find_iter(lines[0])
-        .filter_map(|mat| mat.as_str().parse().ok())
-        .collect();
-
-    let distances: Vec<usize> = num_re
-        .find_iter(lines[1])
-        .filter_map(|mat| mat.as_str().parse().ok())
-        .collect();
-
-    if part2 {
-        let time: String = times.iter().map(|x| x.to_string()).collect();
-        let time = time.parse::<usize>().unwrap();
-
-        let distance: String = distances.iter().map(|x| x.to_string()).collect();
-        let distance = distance.parse::<usize>().unwrap();
-        vec![Race::new(time, distance)]
-    } else {
-        zip(times, distances)
-            .into_iter()
-            .map(|(t, d)| Race::new(t, d))
-            .collect()
+fn describe(race: &Race, physics: &Physics) -> String {
+    let holds = race.winning_holds_with(physics);
+    match race.ways_to_win_with(physics) {
+        0 => format!(
+            "{} ms, record {} mm: no way to win",
+            race.time(),
+            race.record()
+        ),
+        ways => format!(
+            "{} ms, record {} mm: hold {} to {} ms, {} ways",
+            race.time(),
+            race.record(),
+            holds.start(),
+            holds.end(),
+            ways
+        ),
     }
 }
 
-fn part1(input: &str) {
-    let records = parse_records(input, false);
-    let mut nums_per_game = Vec::new();
-
-    for r in records {
-        let mut wins = 0x0;
-        for i in 0..r.time {
-            let boat = Boat::new(i, r.time);
-            if boat.get_final_distance() > r.record {
-                wins += 1;
-            }
-        }
+fn race(physics: Physics, input: &str) -> Result<(), String> {
+    let races = solution::parse::<Day6>(input).map_err(|err| err.to_string())?;
 
-        nums_per_game.push(wins);
+    println!("{:?}", physics);
+    for (i, race) in races.races().iter().enumerate() {
+        println!("race {}: {}", i + 1, describe(race, &physics));
     }
-
-    println!("Part1 {:?}", nums_per_game.iter().product::<i32>());
-}
-
-fn part2(input: &str) {
-    let records = parse_records(input, true);
-    let mut nums_per_game = Vec::new();
-
-    for r in records {
-        let mut wins = 0x0;
-        for i in 0..r.time {
-            let boat = Boat::new(i, r.time);
-            if boat.get_final_distance() > r.record {
-                wins += 1;
-            }
-        }
-
-        nums_per_game.push(wins);
+    let product = races.races().iter().try_fold(1u128, |product, r| {
+        product.checked_mul(r.ways_to_win_with(&physics))
+    });
+    match product {
+        Some(product) => println!("ways multiplied: {}", product),
+        None => println!("ways multiplied: too many for a u128"),
     }
+    println!("one long race: {}", describe(races.merged(), &physics));
 
-    println!("Part2 {:?}", nums_per_game.iter().product::<i32>());
+    Ok(())
 }
 
 fn main() {
-    let input = str::from_utf8(include_bytes!("../input.txt")).unwrap();
-
-    part1(input);
-    part2(input);
+    solution::main_with_modes::<Day6, _>(INPUT, &MODES, parse_physics_args, race);
 }
@@ -0,0 +1,93 @@
+use parsing::ParseError;
+
+/// How a boat picks up speed while the button is held and loses it once
+/// it's let go. The default is the puzzle's: 1 mm/ms of speed per ms held,
+/// no cap and no drag.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Physics {
+    /// mm/ms of speed per ms held.
+    pub acceleration: f64,
+    /// mm/ms, holding longer doesn't go any faster.
+    pub max_speed: Option<f64>,
+    /// Per ms, the speed decays as `e^(-drag * t)` while moving.
+    pub drag: f64,
+}
+
+impl Default for Physics {
+    fn default() -> Physics {
+        Physics {
+            acceleration: 1.0,
+            max_speed: None,
+            drag: 0.0,
+        }
+    }
+}
+
+impl Physics {
+    /// Settings like `acceleration=2,max_speed=30,drag=0.01`, any left out
+    /// keep their default.
+    pub fn parse(text: &str) -> Result<Physics, ParseError> {
+        let mut physics = Physics::default();
+        for (key, value) in parsing::key_values::<f64>(text, text, ",", "=")? {
+            if !(value.is_finite() && value >= 0.0) {
+                let reason = format!("expected {} to be a number of at least 0", key);
+                return Err(ParseError::at(text, key, reason));
+            }
+
+            match key {
+                "acceleration" => physics.acceleration = value,
+                "max_speed" => physics.max_speed = Some(value),
+                "drag" => physics.drag = value,
+                _ => {
+                    let reason = "expected acceleration, max_speed or drag";
+                    return Err(ParseError::at(text, key, reason));
+                }
+            }
+        }
+
+        Ok(physics)
+    }
+
+    pub fn speed(&self, hold_time: f64) -> f64 {
+        let speed = self.acceleration * hold_time;
+        self.max_speed.map_or(speed, |max| speed.min(max))
+    }
+
+    /// How far a boat gets moving for `time` ms at `speed` to start with.
+    pub fn distance(&self, speed: f64, time: f64) -> f64 {
+        if self.drag == 0.0 {
+            return speed * time;
+        }
+
+        speed * -(-self.drag * time).exp_m1() / self.drag
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let physics = Physics::parse("acceleration=2, drag=0.5").unwrap();
+        assert_eq!(physics.acceleration, 2.0);
+        assert_eq!((physics.max_speed, physics.drag), (None, 0.5));
+        assert_eq!(Physics::parse("max_speed=3").unwrap().speed(10.0), 3.0);
+
+        let err = Physics::parse("drag=1,speed=2").err().unwrap();
+        assert_eq!(err.reason, "expected acceleration, max_speed or drag");
+        assert_eq!(err.column, 8);
+        assert!(Physics::parse("drag=-1").is_err());
+    }
+
+    #[test]
+    fn drag_slows_the_boat() {
+        let physics = Physics {
+            drag: 0.1,
+            ..Physics::default()
+        };
+        let far = physics.distance(10.0, 1000.0);
+        assert!((far - 100.0).abs() < 1e-9);
+        assert!(physics.distance(10.0, 5.0) < Physics::default().distance(10.0, 5.0));
+    }
+}
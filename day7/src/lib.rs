@@ -0,0 +1,355 @@
+use common::Solution;
+use parsing::ParseError;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+mod rules;
+
+pub use rules::{RuleSet, NAMES, STANDARD};
+
+/// A card as a [`RuleSet`] sees it: `value` is its rank, lowest first.
+#[derive(PartialEq, Eq, Hash, PartialOrd, Ord, Debug, Clone, Copy)]
+pub struct Card {
+    value: usize,
+    is_wild: bool,
+}
+
+impl Card {
+    pub fn value(&self) -> usize {
+        self.value
+    }
+
+    pub fn is_wild(&self) -> bool {
+        self.is_wild
+    }
+}
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone, Copy)]
+pub enum CardType {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfKind,
+    FullHouse,
+    FourOfKind,
+    FiveOfKind,
+}
+
+impl CardType {
+    /// Lowest first, the usual order.
+    pub const ALL: [CardType; 7] = [
+        CardType::HighCard,
+        CardType::OnePair,
+        CardType::TwoPair,
+        CardType::ThreeOfKind,
+        CardType::FullHouse,
+        CardType::FourOfKind,
+        CardType::FiveOfKind,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            CardType::HighCard => "high-card",
+            CardType::OnePair => "one-pair",
+            CardType::TwoPair => "two-pair",
+            CardType::ThreeOfKind => "three-of-a-kind",
+            CardType::FullHouse => "full-house",
+            CardType::FourOfKind => "four-of-a-kind",
+            CardType::FiveOfKind => "five-of-a-kind",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<CardType> {
+        CardType::ALL.into_iter().find(|t| t.name() == name)
+    }
+
+    /// The type of a hand with groups of equal cards of these sizes.
+    pub fn from_groups(groups: &[usize]) -> CardType {
+        let mut groups = groups.to_vec();
+        groups.sort_unstable_by(|a, b| b.cmp(a));
+
+        match (groups.first(), groups.get(1)) {
+            (Some(&5), _) => CardType::FiveOfKind,
+            (Some(&4), _) => CardType::FourOfKind,
+            (Some(&3), Some(&2)) => CardType::FullHouse,
+            (Some(&3), _) => CardType::ThreeOfKind,
+            (Some(&2), Some(&2)) => CardType::TwoPair,
+
+            (Some(&2), _) => CardType::OnePair,
+            _ => CardType::HighCard,
+        }
+    }
+}
+
+// The best type `rules` allows with `wild` wild cards added to `groups`:
+// each one joins a group or starts a new one, as long as there are kinds
+// of card left to start one with.
+fn best_type(groups: &mut Vec<usize>, wild: usize, kinds: usize, rules: &RuleSet) -> CardType {
+    if wild == 0 {
+        return CardType::from_groups(groups);
+    }
+    if kinds == 0 {
+        // Only wild cards, they can only match each other.
+        return CardType::from_groups(&[wild]);
+    }
+
+    let mut types = Vec::new();
+    for i in 0..groups.len() {
+        groups[i] += 1;
+        types.push(best_type(groups, wild - 1, kinds, rules));
+        groups[i] -= 1;
+    }
+    if groups.len() < kinds {
+        groups.push(1);
+        types.push(best_type(groups, wild - 1, kinds, rules));
+        groups.pop();
+    }
+
+    types
+        .into_iter()
+        .max_by_key(|&typ| rules.strength(typ))
+        .unwrap()
+}
+
+#[derive(Debug)]
+pub struct Hand {
+    cards: Vec<Card>,
+    bid: usize,
+    typ: CardType,
+    // Where `typ` ranks in the rule set.
+    strength: usize,
+}
+
+impl Hand {
+    /// Wild cards play as whatever makes the type `rules` ranks highest.
+    pub fn new(cards: Vec<Card>, bid: usize, rules: &RuleSet) -> Hand {
+        let mut counts = HashMap::new();
+        let wild_cnt = cards.iter().filter(|x| x.is_wild()).count();
+        let cards_for_typ: Vec<&Card> = cards.iter().filter(|c| !c.is_wild()).collect();
+
+        for &item in cards_for_typ.iter() {
+            *counts.entry(item).or_insert(0) += 1;
+        }
+
+        let mut groups: Vec<usize> = counts.values().cloned().collect();
+        let kinds = rules.ranks().len() - rules.wild().len();
+        let typ = best_type(&mut groups, wild_cnt, kinds, rules);
+
+        Hand {
+            cards,
+            bid,
+            strength: rules.strength(typ),
+            typ,
+        }
+    }
+
+    pub fn get_bid(&self) -> usize {
+        self.bid
+    }
+
+    pub fn get_type(&self) -> CardType {
+        self.typ
+    }
+}
+
+impl PartialEq for Hand {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Hand {}
+
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.strength, &self.cards).cmp(&(other.strength, &other.cards))
+    }
+}
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Hands of five cards from `rules`, each with its bid.
+pub fn parse_hands(input: &str, rules: &RuleSet) -> Result<Vec<(String, usize)>, ParseError> {
+    let mut hands = Vec::new();
+    for l in input.lines() {
+        let (cards, bid) = parsing::split_once(input, l, " ")?;
+        if cards.chars().count() != 5 || !cards.chars().all(|c| rules.card(c).is_some()) {
+            return Err(ParseError::at(input, cards, "expected five cards"));
+        }
+        let bid = parsing::number(input, bid)?;
+
+        hands.push((cards.to_string(), bid));
+    }
+
+    Ok(hands)
+}
+
+fn build_hands(hands: &[(String, usize)], rules: &RuleSet) -> Vec<Hand> {
+    hands
+        .iter()
+        .map(|(cards, bid)| {
+            rules
+                .hand(cards, *bid)
+                .expect("hands were checked against the rules")
+        })
+        .collect()
+}
+
+/// The hands must only hold cards `rules` knows, see [`parse_hands`].
+pub fn total_winnings(hands: &[(String, usize)], rules: &RuleSet) -> usize {
+    let mut hands = build_hands(hands, rules);
+    hands.sort();
+
+    let mut earnings = 0;
+
+    for (rank, hand) in hands.iter().enumerate() {
+        earnings += (rank + 1) * hand.get_bid();
+    }
+
+    earnings
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+    type Input = Vec<(String, usize)>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<(String, usize)>, ParseError> {
+        parse_hands(input, &RuleSet::named("standard").unwrap())
+    }
+
+    fn part1(hands: &Vec<(String, usize)>) -> usize {
+        total_winnings(hands, &RuleSet::named("standard").unwrap())
+    }
+
+    fn part2(hands: &Vec<(String, usize)>) -> usize {
+        total_winnings(hands, &RuleSet::named("jokers").unwrap())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day7::part1(&Day7::parse(EXAMPLE).unwrap()), 6440);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day7::part2(&Day7::parse(EXAMPLE).unwrap()), 5905);
+    }
+
+    fn hand_type(cards: &str, rules: &str) -> CardType {
+        let rules = RuleSet::named(rules).unwrap();
+        rules.hand(cards, 0).unwrap().get_type()
+    }
+
+    #[test]
+    fn hand_types() {
+        assert_eq!(hand_type("23456", "standard"), CardType::HighCard);
+        assert_eq!(hand_type("32T3K", "standard"), CardType::OnePair);
+        assert_eq!(hand_type("KK677", "standard"), CardType::TwoPair);
+        assert_eq!(hand_type("KTJJT", "standard"), CardType::TwoPair);
+        assert_eq!(hand_type("T55J5", "standard"), CardType::ThreeOfKind);
+        assert_eq!(hand_type("23332", "standard"), CardType::FullHouse);
+        assert_eq!(hand_type("AA8AA", "standard"), CardType::FourOfKind);
+        assert_eq!(hand_type("AAAAA", "standard"), CardType::FiveOfKind);
+    }
+
+    #[test]
+    fn hand_types_with_jokers() {
+        assert_eq!(hand_type("32T3K", "jokers"), CardType::OnePair);
+        assert_eq!(hand_type("2345J", "jokers"), CardType::OnePair);
+        assert_eq!(hand_type("KK677", "jokers"), CardType::TwoPair);
+        assert_eq!(hand_type("2233J", "jokers"), CardType::FullHouse);
+        assert_eq!(hand_type("T55J5", "jokers"), CardType::FourOfKind);
+        assert_eq!(hand_type("KTJJT", "jokers"), CardType::FourOfKind);
+        assert_eq!(hand_type("QQQJA", "jokers"), CardType::FourOfKind);
+        assert_eq!(hand_type("JJJJJ", "jokers"), CardType::FiveOfKind);
+    }
+
+    #[test]
+    fn several_wild_cards() {
+        assert_eq!(
+            hand_type("2J345", "jokers-and-deuces"),
+            CardType::ThreeOfKind
+        );
+        assert_eq!(
+            hand_type("22JJ5", "jokers-and-deuces"),
+            CardType::FiveOfKind
+        );
+        assert_eq!(
+            hand_type("2JKQA", "jokers-and-deuces"),
+            CardType::ThreeOfKind
+        );
+        assert_eq!(hand_type("2KKQQ", "deuces-wild"), CardType::FullHouse);
+        assert_eq!(
+            hand_type("22JJJ", "jokers-and-deuces"),
+            CardType::FiveOfKind
+        );
+
+        let rules = RuleSet::named("jokers-and-deuces").unwrap();
+        assert_eq!(rules.ranks()[..3], ['2', 'J', '3']);
+        assert!(rules.hand("2KKKK", 0) < rules.hand("JKKKK", 0));
+        assert!(rules.hand("JKQ34", 0) < rules.hand("3KQ34", 0));
+    }
+
+    #[test]
+    fn other_rule_sets() {
+        let aces_low = RuleSet::named("aces-low").unwrap();
+        assert!(aces_low.hand("A2345", 0) < aces_low.hand("23456", 0));
+        let hands = Day7::parse("A2345 1\n23456 2\n").unwrap();
+        assert_eq!(
+            total_winnings(&hands, &RuleSet::named("standard").unwrap()),
+            4
+        );
+        assert_eq!(total_winnings(&hands, &aces_low), 5);
+
+        let text = "# pairs beat everything\ncards = vxyzw\nwild = w\n\
+            types = high-card, two-pair, three-of-a-kind, full-house, four-of-a-kind, \
+            five-of-a-kind, one-pair\n";
+        let rules = RuleSet::parse(text).unwrap();
+        assert_eq!(rules.ranks(), ['w', 'v', 'x', 'y', 'z']);
+        assert!(rules.hand("xxyyz", 0) < rules.hand("xyzzz", 0));
+        assert!(rules.hand("xxxxx", 0) < rules.hand("vxyzz", 0));
+        // w plays as v, the pair beats the full house.
+        assert_eq!(
+            rules.hand("xyzzw", 0).unwrap().get_type(),
+            CardType::OnePair
+        );
+        let hands = parse_hands("xyzzw 10\nxxxyy 3\n", &rules).unwrap();
+        assert_eq!(total_winnings(&hands, &rules), 3 + 2 * 10);
+        assert!(parse_hands("xyzzA 10\n", &rules).is_err());
+    }
+
+    #[test]
+    fn invalid_rules() {
+        assert!(RuleSet::new("23456", "J").is_err());
+        assert!(RuleSet::new("2345 6", "").is_err());
+        assert!(RuleSet::new("23452", "").is_err());
+
+        let err = RuleSet::parse("cards = 234\nwidl = 2\n").err().unwrap();
+        assert_eq!(
+            (err.line, err.reason.as_str()),
+            (2, "expected cards, wild or types")
+        );
+        let err = RuleSet::parse("cards = 234\ntypes = high-card, pair\n")
+            .err()
+            .unwrap();
+        assert_eq!(err.text, "pair");
+        let err = RuleSet::parse("wild = 2\n").err().unwrap();
+        assert_eq!(err.reason, "expected a cards line");
+        assert!(RuleSet::parse("cards = 234\ntypes = high-card\n").is_err());
+    }
+}
@@ -0,0 +1,169 @@
+use crate::{Card, CardType, Hand};
+use common::input;
+use parsing::ParseError;
+
+/// The cards of the puzzle, lowest first.
+pub const STANDARD: &str = "23456789TJQKA";
+
+/// The rule sets [`RuleSet::named`] knows.
+pub const NAMES: &[&str] = &[
+    "standard",
+    "jokers",
+    "aces-low",
+    "deuces-wild",
+    "jokers-and-deuces",
+];
+
+/// How to play: which cards there are and how they rank, which are wild and
+/// how the hand types rank.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuleSet {
+    // Lowest first, the wild cards come before all the others.
+    ranks: Vec<char>,
+    wild: Vec<char>,
+    // Lowest first.
+    types: Vec<CardType>,
+}
+
+impl RuleSet {
+    /// `cards` lowest first. Wild cards stand in for whatever makes the best
+    /// hand, and rank below every other card when breaking ties.
+    pub fn new(cards: &str, wild: &str) -> Result<RuleSet, String> {
+        let cards: Vec<char> = cards.chars().collect();
+        if cards.is_empty() {
+            return Err("expected some cards".to_string());
+        }
+        for (i, &c) in cards.iter().enumerate() {
+            if c.is_whitespace() {
+                return Err("cards can't be whitespace".to_string());
+            }
+            if cards[..i].contains(&c) {
+                return Err(format!("card {} is listed twice", c));
+            }
+        }
+
+        if let Some(c) = wild.chars().find(|c| !cards.contains(c)) {
+            return Err(format!("wild card {} isn't one of the cards", c));
+        }
+        let wild: Vec<char> = cards
+            .iter()
+            .copied()
+            .filter(|&c| wild.contains(c))
+            .collect();
+
+        let ranks = wild
+            .iter()
+            .chain(cards.iter().filter(|c| !wild.contains(c)))
+            .copied()
+            .collect();
+        Ok(RuleSet {
+            ranks,
+            wild,
+            types: CardType::ALL.to_vec(),
+        })
+    }
+
+    /// Ranks the hand types in this order, lowest first. Every type has to
+    /// be there once.
+    pub fn with_types(self, types: Vec<CardType>) -> Result<RuleSet, String> {
+        if let Some(typ) = CardType::ALL.iter().find(|t| !types.contains(t)) {
+            return Err(format!("the hand types are missing {}", typ.name()));
+        }
+        if types.len() != CardType::ALL.len() {
+            return Err("every hand type has to be listed once".to_string());
+        }
+
+        Ok(RuleSet { types, ..self })
+    }
+
+    /// One of [`NAMES`].
+    pub fn named(name: &str) -> Option<RuleSet> {
+        let (cards, wild) = match name {
+            "standard" => (STANDARD, ""),
+            "jokers" => (STANDARD, "J"),
+            "aces-low" => ("A23456789TJQK", ""),
+            "deuces-wild" => (STANDARD, "2"),
+            "jokers-and-deuces" => (STANDARD, "2J"),
+            _ => return None,
+        };
+
+        Some(RuleSet::new(cards, wild).expect("built in rule sets are valid"))
+    }
+
+    /// Rules with a `key = value` line per setting, see
+    /// [`parsing::settings`]:
+    ///
+    /// - `cards`, the cards lowest first, required
+    /// - `wild`, the wild cards
+    /// - `types`, the hand types lowest first, separated by commas
+    pub fn parse(text: &str) -> Result<RuleSet, ParseError> {
+        let (mut cards, mut wild, mut types) = (None, "", None);
+        for (key, value) in parsing::settings(text)? {
+            match key {
+                "cards" => cards = Some(value),
+                "wild" => wild = value,
+                "types" => types = Some(value),
+                _ => {
+                    let reason = "expected cards, wild or types";
+                    return Err(ParseError::at(text, key, reason));
+                }
+            }
+        }
+
+        let end = &text[text.len()..];
+        let cards = cards.ok_or_else(|| ParseError::at(text, end, "expected a cards line"))?;
+        let rules = RuleSet::new(cards, wild).map_err(|err| ParseError::at(text, cards, err))?;
+        let Some(types) = types else {
+            return Ok(rules);
+        };
+
+        let types = types
+            .split(',')
+            .map(|name| {
+                CardType::from_name(name.trim())
+                    .ok_or_else(|| ParseError::at(text, name.trim(), "expected a hand type"))
+            })
+            .collect::<Result<_, _>>()?;
+        rules
+            .with_types(types)
+            .map_err(|err| ParseError::at(text, cards, err))
+    }
+
+    /// A rule set by name if `name` is one, otherwise a rules file, see
+    /// [`RuleSet::parse`].
+    pub fn load(name: &str) -> Result<RuleSet, String> {
+        input::builtin_or_file(name, RuleSet::named, RuleSet::parse)
+    }
+
+    /// The cards lowest first, wild ones first of all.
+    pub fn ranks(&self) -> &[char] {
+        &self.ranks
+    }
+
+    pub fn wild(&self) -> &[char] {
+        &self.wild
+    }
+
+    pub fn types(&self) -> &[CardType] {
+        &self.types
+    }
+
+    pub fn card(&self, c: char) -> Option<Card> {
+        let value = self.ranks.iter().position(|&r| r == c)?;
+        Some(Card {
+            value,
+            is_wild: self.wild.contains(&c),
+        })
+    }
+
+    /// `None` if one of the cards isn't in this rule set.
+    pub fn hand(&self, cards: &str, bid: usize) -> Option<Hand> {
+        let cards = cards.chars().map(|c| self.card(c)).collect::<Option<_>>()?;
+        Some(Hand::new(cards, bid, self))
+    }
+
+    /// Where `typ` ranks, lowest first.
+    pub fn strength(&self, typ: CardType) -> usize {
+        self.types.iter().position(|&t| t == typ).unwrap()
+    }
+}
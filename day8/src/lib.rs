@@ -0,0 +1,163 @@
+use common::Solution;
+use num::integer::lcm;
+use parsing::ParseError;
+use std::collections::HashMap;
+
+fn parse_instructions(input: &str, line: &str) -> Result<Vec<usize>, ParseError> {
+    line.char_indices()
+        .map(|(i, c)| match c {
+            'L' => Ok(0),
+            'R' => Ok(1),
+            _ => Err(ParseError::at(input, &line[i..], "expected L or R")),
+        })
+        .collect()
+}
+
+// AAA = (BBB, CCC)
+fn parse_node<'a>(input: &str, line: &'a str) -> Result<(&'a str, [&'a str; 2]), ParseError> {
+    let (node, next) = parsing::split_once(input, line, " = ")?;
+    let next = parsing::delimited(input, next, "(", ")")?;
+    let (left, right) = parsing::split_once(input, next, ", ")?;
+
+    Ok((node, [left, right]))
+}
+
+fn parse_map(input: &str, block: &str) -> Result<HashMap<String, (String, String)>, ParseError> {
+    let nodes = block
+        .lines()
+        .map(|l| parse_node(input, l))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut map = HashMap::new();
+    for &(node, [left, right]) in nodes.iter() {
+        let next = (left.to_string(), right.to_string());
+        if map.insert(node.to_string(), next).is_some() {
+            let reason = format!("expected only one node {}", node);
+            return Err(ParseError::at(input, node, reason));
+        }
+    }
+
+    // Every step has to land on a node.
+    for (_, next) in nodes.iter() {
+        if let Some(name) = next.iter().find(|name| !map.contains_key(**name)) {
+            return Err(ParseError::at(input, name, "expected a node that's listed"));
+        }
+    }
+
+    Ok(map)
+}
+
+pub struct Network {
+    insts: Vec<usize>,
+    map: HashMap<String, (String, String)>,
+}
+
+impl Network {
+    // The steps from `start` to the first node that is `done`, `None` if it
+    // never gets there.
+    fn steps(&self, start: &str, done: impl Fn(&str) -> bool) -> Option<usize> {
+        // After this many steps some node has been left by the same
+        // instruction twice, from there on it goes round in circles.
+        let limit = self.map.len() * self.insts.len();
+
+        let mut pos = start;
+        for (steps, inst) in self.insts.iter().cycle().take(limit).enumerate() {
+            pos = match inst {
+                1 => &self.map[pos].1,
+                _ => &self.map[pos].0,
+            };
+            if done(pos) {
+                return Some(steps + 1);
+            }
+        }
+
+        None
+    }
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+    type Input = Network;
+    // `None` if the nodes aren't there or don't lead to the end.
+    type Part1 = Option<usize>;
+    type Part2 = Option<usize>;
+
+    fn parse(input: &str) -> Result<Network, ParseError> {
+        let mut blocks = parsing::blocks(input);
+        let (Some(insts), Some(map), None) = (blocks.next(), blocks.next(), blocks.next()) else {
+            let reason = "expected instructions and nodes separated by a blank line";
+            return Err(ParseError::at(input, input, reason));
+        };
+        let insts = parse_instructions(input, insts)?;
+        let map = parse_map(input, map)?;
+
+        Ok(Network { insts, map })
+    }
+
+    fn part1(network: &Network) -> Option<usize> {
+        if !network.map.contains_key("AAA") {
+            return None;
+        }
+
+        network.steps("AAA", |pos| pos == "ZZZ")
+    }
+
+    fn part2(network: &Network) -> Option<usize> {
+        let positions: Vec<&str> = network
+            .map
+            .keys()
+            .filter(|k| k.ends_with('A'))
+            .map(String::as_str)
+            .collect();
+        if positions.is_empty() {
+            return None;
+        }
+
+        positions.iter().try_fold(1, |acc, start| {
+            let steps = network.steps(start, |pos| pos.ends_with('Z'))?;
+            Some(lcm(acc, steps))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+    const EXAMPLE2: &str = include_str!("../example2.txt");
+    const EXAMPLE3: &str = include_str!("../example3.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day8::part1(&Day8::parse(EXAMPLE).unwrap()), Some(2));
+        assert_eq!(Day8::part1(&Day8::parse(EXAMPLE2).unwrap()), Some(6));
+        assert_eq!(Day8::part1(&Day8::parse(EXAMPLE3).unwrap()), None);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day8::part2(&Day8::parse(EXAMPLE3).unwrap()), Some(6));
+    }
+
+    #[test]
+    fn dead_ends() {
+        let network = Day8::parse("L\n\nAAA = (BBB, ZZZ)\nBBB = (AAA, AAA)\nZZZ = (ZZZ, ZZZ)\n");
+        assert_eq!(Day8::part1(&network.unwrap()), None);
+
+        let err = Day8::parse("L\n\nAAA = (BBB, ZZZ)\nZZZ = (ZZZ, ZZZ)\n")
+            .err()
+            .unwrap();
+        assert_eq!((err.line, err.column), (3, 8));
+        assert_eq!(err.reason, "expected a node that's listed");
+        let err = Day8::parse("L\n\nAAA = (AAA, AAA)\nAAA = (AAA, AAA)\n")
+            .err()
+            .unwrap();
+        assert_eq!(
+            (err.line, err.reason.as_str()),
+            (4, "expected only one node AAA")
+        );
+    }
+}
@@ -0,0 +1,85 @@
+use common::Solution;
+use parsing::ParseError;
+
+fn parse_values(input: &str) -> Result<Vec<Vec<i64>>, ParseError> {
+    input
+        .lines()
+        .map(|line| parsing::numbers(input, line))
+        .collect()
+}
+
+fn calc_next_value(l: Vec<i64>) -> i64 {
+    if l.iter().all(|x| *x == 0) {
+        return 0;
+    }
+
+    let diffs: Vec<i64> = l.windows(2).map(|w| w[1] - w[0]).collect();
+    l.last().unwrap() + calc_next_value(diffs)
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+    type Input = Vec<Vec<i64>>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Vec<Vec<i64>>, ParseError> {
+        parse_values(input)
+    }
+
+    fn part1(nums: &Vec<Vec<i64>>) -> i64 {
+        let new_vals: Vec<i64> = nums.iter().cloned().map(calc_next_value).collect();
+
+        new_vals.iter().sum::<i64>()
+    }
+
+    fn part2(nums: &Vec<Vec<i64>>) -> i64 {
+        let new_vals: Vec<i64> = nums
+            .iter()
+            .cloned()
+            .map(|mut v| {
+                v.reverse();
+                calc_next_value(v)
+            })
+            .collect();
+
+        new_vals.iter().sum::<i64>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day9::part1(&Day9::parse(EXAMPLE).unwrap()), 114);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day9::part2(&Day9::parse(EXAMPLE).unwrap()), 2);
+    }
+
+    #[test]
+    fn next_value() {
+        assert_eq!(calc_next_value(vec![0, 3, 6, 9, 12, 15]), 18);
+        assert_eq!(calc_next_value(vec![1, 3, 6, 10, 15, 21]), 28);
+        assert_eq!(calc_next_value(vec![10, 13, 16, 21, 30, 45]), 68);
+        assert_eq!(calc_next_value(vec![45, 30, 21, 16, 13, 10]), 5);
+        assert_eq!(calc_next_value(vec![0, 0, 0]), 0);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let err = Day9::parse("1 2\n0 3 6 x 9 12\n").err().unwrap();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 7, "x"));
+        let err = Day9::parse("0 3 6 9.5 12\n").err().unwrap();
+        assert_eq!((err.column, err.reason.as_str()), (7, "expected a number"));
+        assert_eq!(Day9::parse("-1 -2\n").unwrap(), [[-1, -2]]);
+    }
+}
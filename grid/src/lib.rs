@@ -0,0 +1,333 @@
+pub mod geometry;
+
+pub use geometry::{Direction, Point};
+
+use common::ParseError;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+const OFFSETS4: [Point; 4] = [
+    Point::new(-1, 0),
+    Point::new(0, 1),
+    Point::new(1, 0),
+    Point::new(0, -1),
+];
+const OFFSETS8: [Point; 8] = [
+    Point::new(-1, -1),
+    Point::new(-1, 0),
+    Point::new(-1, 1),
+    Point::new(0, 1),
+    Point::new(1, 1),
+    Point::new(1, 0),
+    Point::new(1, -1),
+    Point::new(0, -1),
+];
+
+/// A rectangular grid stored row by row. Positions are `(row, col)`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    rows: usize,
+    cols: usize,
+}
+
+impl Grid<char> {
+    /// One row per line, one cell per character.
+    pub fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+        Grid::parse_with(input, Some)
+    }
+
+    /// Like [`Grid::parse`], but only accepts the characters in `allowed`.
+    pub fn parse_only(input: &str, allowed: &str) -> Result<Grid<char>, ParseError> {
+        Grid::parse_with(input, |c| allowed.contains(c).then_some(c))
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn new(rows: usize, cols: usize, fill: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            cells: vec![fill; rows * cols],
+            rows,
+            cols,
+        }
+    }
+
+    /// Panics if the rows don't all have the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Grid<T> {
+        let cols = rows.first().map_or(0, |r| r.len());
+        let n_rows = rows.len();
+        let mut cells = Vec::with_capacity(n_rows * cols);
+        for (i, row) in rows.into_iter().enumerate() {
+            assert!(
+                row.len() == cols,
+                "row {} has {} columns, expected {}",
+                i,
+                row.len(),
+                cols
+            );
+            cells.extend(row);
+        }
+
+        Grid {
+            cells,
+            rows: n_rows,
+            cols,
+        }
+    }
+
+    /// One row per line, each character is turned into a cell by `f`, which
+    /// returns `None` for characters that don't belong in the grid.
+    pub fn parse_with(
+        input: &str,
+        mut f: impl FnMut(char) -> Option<T>,
+    ) -> Result<Grid<T>, ParseError> {
+        let mut rows = Vec::new();
+        for line in input.lines() {
+            let mut row = Vec::with_capacity(line.len());
+            for (i, c) in line.char_indices() {
+                let cell = f(c).ok_or_else(|| {
+                    let text = &line[i..i + c.len_utf8()];
+                    ParseError::at(input, text, "unexpected character")
+                })?;
+                row.push(cell);
+            }
+
+            if let Some(first) = rows.first().map(Vec::len) {
+                if row.len() != first {
+                    let reason = format!("expected a row of {} cells, got {}", first, row.len());
+                    return Err(ParseError::at(input, line, reason));
+                }
+            }
+            rows.push(row);
+        }
+
+        Ok(Grid::from_rows(rows))
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.cols..(row + 1) * self.cols]
+    }
+
+    pub fn row_mut(&mut self, row: usize) -> &mut [T] {
+        &mut self.cells[row * self.cols..(row + 1) * self.cols]
+    }
+
+    pub fn iter_rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks() panics on a chunk size of 0
+        self.cells.chunks(self.cols.max(1)).take(self.rows)
+    }
+
+    pub fn col(&self, col: usize) -> impl Iterator<Item = &T> {
+        (0..self.rows).map(move |row| &self[(row, col)])
+    }
+
+    /// Converts a point to an index into the grid, if it's inside.
+    pub fn checked(&self, Point { row, col }: Point) -> Option<(usize, usize)> {
+        let in_bounds =
+            row >= 0 && col >= 0 && row < self.rows as isize && col < self.cols as isize;
+        in_bounds.then_some((row as usize, col as usize))
+    }
+
+    pub fn contains(&self, pos: Point) -> bool {
+        self.checked(pos).is_some()
+    }
+
+    pub fn get(&self, pos: Point) -> Option<&T> {
+        self.checked(pos).map(|pos| &self[pos])
+    }
+
+    pub fn get_mut(&mut self, pos: Point) -> Option<&mut T> {
+        self.checked(pos).map(|pos| &mut self[pos])
+    }
+
+    /// Indexes the grid as if it was tiled infinitely in every direction.
+    pub fn get_wrapping(&self, Point { row, col }: Point) -> &T {
+        let row = row.rem_euclid(self.rows as isize) as usize;
+        let col = col.rem_euclid(self.cols as isize) as usize;
+
+        &self[(row, col)]
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let cols = self.cols;
+        (0..self.rows).flat_map(move |row| (0..cols).map(move |col| (row, col)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn position(&self, f: impl Fn(&T) -> bool) -> Option<(usize, usize)> {
+        self.iter().find(|(_, v)| f(v)).map(|(pos, _)| pos)
+    }
+
+    /// The up to 4 orthogonal neighbours of `pos` that are inside the grid.
+    pub fn neighbours4(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbours(pos, &OFFSETS4)
+    }
+
+    /// The up to 8 neighbours of `pos`, diagonals included, that are inside
+    /// the grid.
+    pub fn neighbours8(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbours(pos, &OFFSETS8)
+    }
+
+    fn neighbours<'a>(
+        &'a self,
+        pos: (usize, usize),
+        offsets: &'static [Point],
+    ) -> impl Iterator<Item = (usize, usize)> + 'a {
+        offsets
+            .iter()
+            .filter_map(move |&delta| self.checked(Point::from(pos) + delta))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            rows: self.rows,
+            cols: self.cols,
+        }
+    }
+
+    pub fn transpose(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.remap(self.cols, self.rows, |row, col| (col, row))
+    }
+
+    /// Rotates the grid a quarter turn clockwise.
+    pub fn rotate_cw(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.remap(self.cols, self.rows, |row, col| (self.rows - 1 - col, row))
+    }
+
+    /// Rotates the grid a quarter turn counter-clockwise.
+    pub fn rotate_ccw(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.remap(self.cols, self.rows, |row, col| (col, self.cols - 1 - row))
+    }
+
+    // Builds a `rows` x `cols` grid where every cell is copied from the
+    // position `from` returns for it.
+    fn remap(
+        &self,
+        rows: usize,
+        cols: usize,
+        from: impl Fn(usize, usize) -> (usize, usize),
+    ) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let mut cells = Vec::with_capacity(rows * cols);
+        for row in 0..rows {
+            for col in 0..cols {
+                cells.push(self[from(row, col)].clone());
+            }
+        }
+
+        Grid { cells, rows, cols }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        assert!(col < self.cols, "column {} out of bounds", col);
+        &self.cells[row * self.cols + col]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        assert!(col < self.cols, "column {} out of bounds", col);
+        &mut self.cells[row * self.cols + col]
+    }
+}
+
+/// Renders the grid back to text, one line per row.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.iter_rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parsing() {
+        let grid = Grid::parse("ab\ncd\n").unwrap();
+        assert_eq!((grid.rows(), grid.cols()), (2, 2));
+        assert_eq!(grid.row(1), ['c', 'd']);
+        assert_eq!(grid.to_string(), "ab\ncd\n");
+
+        let text = "ab\nc\n";
+        let err = Grid::parse(text).unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 1, "c"));
+        assert_eq!(err.reason, "expected a row of 2 cells, got 1");
+
+        let err = Grid::parse_only(".#\n#x\n", ".#").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 2));
+        assert_eq!(err.reason, "unexpected character");
+    }
+
+    #[test]
+    fn rotating() {
+        let grid = Grid::parse("abc\ndef\n").unwrap();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(grid.rotate_cw().to_string(), "da\neb\nfc\n");
+        assert_eq!(grid.rotate_ccw().to_string(), "cf\nbe\nad\n");
+        assert_eq!(grid.rotate_cw().rotate_ccw(), grid);
+    }
+
+    #[test]
+    fn wrapping() {
+        let grid = Grid::parse("abc\ndef\n").unwrap();
+        assert_eq!(grid.get(Point::new(-1, 0)), None);
+        assert_eq!(grid.get_wrapping(Point::new(-1, 0)), &'d');
+        assert_eq!(grid.get_wrapping(Point::new(0, -1)), &'c');
+        assert_eq!(grid.get_wrapping(Point::new(-3, -4)), &'f');
+        assert_eq!(grid.get_wrapping(Point::new(4, 7)), &'b');
+    }
+
+    #[test]
+    fn neighbours_at_the_edges() {
+        let grid = Grid::new(3, 4, 0);
+        let corner: Vec<_> = grid.neighbours4((0, 0)).collect();
+        assert_eq!(corner, [(0, 1), (1, 0)]);
+        let edge: Vec<_> = grid.neighbours4((2, 1)).collect();
+        assert_eq!(edge, [(1, 1), (2, 2), (2, 0)]);
+        assert_eq!(grid.neighbours4((1, 1)).count(), 4);
+
+        let corner: Vec<_> = grid.neighbours8((2, 3)).collect();
+        assert_eq!(corner, [(1, 2), (1, 3), (2, 2)]);
+        assert_eq!(grid.neighbours8((0, 1)).count(), 5);
+        assert_eq!(grid.neighbours8((1, 2)).count(), 8);
+    }
+}
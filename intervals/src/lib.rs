@@ -0,0 +1,204 @@
+use std::ops::Range;
+
+/// A set of values stored as half-open ranges. The ranges are always
+/// normalised: sorted, non-empty, and neither overlapping nor touching, so
+/// two sets with the same values compare equal.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    ranges: Vec<Range<T>>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        IntervalSet { ranges: Vec::new() }
+    }
+}
+
+impl<T: Ord + Copy> IntervalSet<T> {
+    pub fn new() -> Self {
+        IntervalSet::default()
+    }
+
+    /// Any ranges: empty ones are dropped, the rest sorted and merged.
+    pub fn from_ranges(ranges: impl IntoIterator<Item = Range<T>>) -> Self {
+        let mut ranges: Vec<Range<T>> = ranges.into_iter().filter(|r| r.start < r.end).collect();
+        ranges.sort_by_key(|r| r.start);
+
+        let mut merged: Vec<Range<T>> = Vec::with_capacity(ranges.len());
+        for range in ranges {
+            match merged.last_mut() {
+                Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+                _ => merged.push(range),
+            }
+        }
+
+        IntervalSet { ranges: merged }
+    }
+
+    pub fn ranges(&self) -> &[Range<T>] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    pub fn min(&self) -> Option<T> {
+        self.ranges.first().map(|r| r.start)
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let i = self.ranges.partition_point(|r| r.end <= value);
+        self.ranges.get(i).is_some_and(|r| r.start <= value)
+    }
+
+    pub fn insert(&mut self, range: Range<T>) {
+        let ranges = std::mem::take(&mut self.ranges);
+        *self = IntervalSet::from_ranges(ranges.into_iter().chain([range]));
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        IntervalSet::from_ranges(self.ranges.iter().chain(&other.ranges).cloned())
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while let (Some(a), Some(b)) = (self.ranges.get(i), other.ranges.get(j)) {
+            let start = a.start.max(b.start);
+            let end = a.end.min(b.end);
+            if start < end {
+                ranges.push(start..end);
+            }
+            // Whichever ends first can't overlap anything else.
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        IntervalSet { ranges }
+    }
+
+    /// The values in `self` that aren't in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let mut j = 0;
+        for range in self.ranges.iter() {
+            let mut start = range.start;
+            // Skip what ends before this range, it's the same for the next.
+            while other.ranges.get(j).is_some_and(|r| r.end <= start) {
+                j += 1;
+            }
+
+            let mut k = j;
+            while let Some(cut) = other.ranges.get(k).filter(|r| r.start < range.end) {
+                if start < cut.start {
+                    ranges.push(start..cut.start);
+                }
+                start = start.max(cut.end);
+                k += 1;
+            }
+            if start < range.end {
+                ranges.push(start..range.end);
+            }
+        }
+
+        IntervalSet { ranges }
+    }
+}
+
+impl<T: Ord + Copy> From<Range<T>> for IntervalSet<T> {
+    fn from(range: Range<T>) -> Self {
+        IntervalSet::from_ranges([range])
+    }
+}
+
+impl<T: Ord + Copy> FromIterator<Range<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(iter: I) -> Self {
+        IntervalSet::from_ranges(iter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use std::collections::BTreeSet;
+
+    const MAX: u8 = 64;
+
+    fn values(set: &IntervalSet<u8>) -> BTreeSet<u8> {
+        set.ranges().iter().flat_map(|r| r.clone()).collect()
+    }
+
+    fn is_normalised(set: &IntervalSet<u8>) -> bool {
+        let ranges = set.ranges();
+        ranges.iter().all(|r| r.start < r.end) && ranges.windows(2).all(|w| w[0].end < w[1].start)
+    }
+
+    fn set() -> impl Strategy<Value = IntervalSet<u8>> {
+        prop::collection::vec((0..MAX, 0..MAX).prop_map(|(a, b)| a..b), 0..8)
+            .prop_map(IntervalSet::from_ranges)
+    }
+
+    #[test]
+    fn normalising() {
+        let set =
+            IntervalSet::from_ranges([5..7, 1..3, 3..4, 6..10, 12..12, Range { start: 9, end: 8 }]);
+        assert_eq!(set.ranges(), [1..4, 5..10]);
+        assert_eq!(set.min(), Some(1));
+        assert!(set.contains(3) && !set.contains(4) && set.contains(9) && !set.contains(10));
+
+        let mut set = IntervalSet::new();
+        set.insert(10..20);
+        set.insert(20..25);
+        assert_eq!(set, IntervalSet::from(10..25));
+    }
+
+    #[test]
+    fn operations() {
+        let a = IntervalSet::from_ranges([0..10, 20..30]);
+        let b = IntervalSet::from(5..25);
+        assert_eq!(a.union(&b), IntervalSet::from(0..30));
+        assert_eq!(a.intersection(&b).ranges(), [5..10, 20..25]);
+        assert_eq!(a.difference(&b).ranges(), [0..5, 25..30]);
+        assert_eq!(b.difference(&a), IntervalSet::from(10..20));
+    }
+
+    proptest! {
+        #[test]
+        fn from_ranges_keeps_the_values(ranges in prop::collection::vec((0..MAX, 0..MAX), 0..8)) {
+            let ranges: Vec<Range<u8>> = ranges.into_iter().map(|(a, b)| a..b).collect();
+            let set = IntervalSet::from_ranges(ranges.clone());
+            let expected: BTreeSet<u8> = ranges.into_iter().flatten().collect();
+            prop_assert!(is_normalised(&set));
+            prop_assert_eq!(values(&set), expected.clone());
+            for x in 0..MAX {
+                prop_assert_eq!(set.contains(x), expected.contains(&x));
+            }
+        }
+
+        #[test]
+        fn set_operations(a in set(), b in set()) {
+            let (va, vb) = (values(&a), values(&b));
+            let results = [
+                (a.union(&b), va.union(&vb).copied().collect::<BTreeSet<_>>()),
+                (a.intersection(&b), va.intersection(&vb).copied().collect()),
+                (a.difference(&b), va.difference(&vb).copied().collect()),
+            ];
+            for (set, expected) in results {
+                prop_assert!(is_normalised(&set));
+                prop_assert_eq!(values(&set), expected);
+            }
+        }
+
+        #[test]
+        fn difference_and_intersection_split_a_set(a in set(), b in set()) {
+            let parts = a.difference(&b).union(&a.intersection(&b));
+            prop_assert_eq!(parts, a.clone());
+            prop_assert!(a.difference(&b).intersection(&b).is_empty());
+        }
+    }
+}
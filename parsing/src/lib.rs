@@ -0,0 +1,271 @@
+//! Helpers for taking puzzle inputs apart. Like [`common::parse`], they all
+//! take the whole `input` next to the `text` being parsed, a slice of it, so
+//! errors point at the right line and column.
+
+pub use common::parse::{number, split_once};
+pub use common::ParseError;
+
+use std::any;
+use std::iter;
+use std::str::FromStr;
+
+// Every run of digits in `text`, with its minus sign if `signed`.
+fn extract<T: FromStr>(input: &str, text: &str, signed: bool) -> Result<Vec<T>, ParseError> {
+    let bytes = text.as_bytes();
+    let mut values = Vec::new();
+
+    let mut i = 0;
+    while i < bytes.len() {
+        let start = i;
+        if signed && bytes[i] == b'-' && bytes.get(i + 1).is_some_and(u8::is_ascii_digit) {
+            i += 1;
+        }
+        if !bytes[i].is_ascii_digit() {
+            i += 1;
+            continue;
+        }
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+
+        let digits = &text[start..i];
+        let value = digits.parse().map_err(|_| {
+            let reason = format!("expected a number that fits in {}", any::type_name::<T>());
+            ParseError::at(input, digits, reason)
+        })?;
+        values.push(value);
+    }
+
+    Ok(values)
+}
+
+/// Every run of digits in `text`, skipping whatever is between them. Minus
+/// signs are skipped too, see [`signed_ints`].
+pub fn ints<T: FromStr>(input: &str, text: &str) -> Result<Vec<T>, ParseError> {
+    extract(input, text, false)
+}
+
+/// Like [`ints`], but a `-` right in front of the digits makes a number
+/// negative, so `1-2` is 1 and -2.
+pub fn signed_ints<T: FromStr>(input: &str, text: &str) -> Result<Vec<T>, ParseError> {
+    extract(input, text, true)
+}
+
+/// Numbers separated by whitespace and nothing else.
+pub fn numbers<T: FromStr>(input: &str, text: &str) -> Result<Vec<T>, ParseError> {
+    text.split_whitespace().map(|x| number(input, x)).collect()
+}
+
+/// Numbers separated by `sep`, with optional whitespace around them.
+pub fn list<T: FromStr>(input: &str, text: &str, sep: &str) -> Result<Vec<T>, ParseError> {
+    text.split(sep).map(|x| number(input, x)).collect()
+}
+
+/// Like [`list`], but there have to be exactly `N` numbers.
+pub fn array<T: FromStr, const N: usize>(
+    input: &str,
+    text: &str,
+    sep: &str,
+) -> Result<[T; N], ParseError> {
+    list(input, text, sep)?.try_into().map_err(|_| {
+        let reason = format!("expected {} numbers separated by {:?}", N, sep);
+        ParseError::at(input, text, reason)
+    })
+}
+
+/// `str::strip_prefix` that reports a missing prefix.
+pub fn prefixed<'a>(input: &str, text: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+    text.strip_prefix(prefix)
+        .ok_or_else(|| ParseError::at(input, text, format!("expected {:?}", prefix)))
+}
+
+/// What's between `open` and `close`, which have to be at the very start
+/// and end of `text`.
+pub fn delimited<'a>(
+    input: &str,
+    text: &'a str,
+    open: &str,
+    close: &str,
+) -> Result<&'a str, ParseError> {
+    text.strip_prefix(open)
+        .and_then(|inner| inner.strip_suffix(close))
+        .ok_or_else(|| {
+            let reason = format!("expected \"{}...{}\"", open, close);
+            ParseError::at(input, text, reason)
+        })
+}
+
+/// Keys with their values, e.g. `x=787,m=2655` with `sep` "," and `assign`
+/// "=".
+pub fn key_values<'a, T: FromStr>(
+    input: &str,
+    text: &'a str,
+    sep: &str,
+    assign: &str,
+) -> Result<Vec<(&'a str, T)>, ParseError> {
+    text.split(sep)
+        .map(|pair| {
+            let (key, value) = split_once(input, pair, assign)?;
+            Ok((key.trim(), number(input, value)?))
+        })
+        .collect()
+}
+
+/// The `key = value` lines of a settings file, both sides trimmed. Blank
+/// lines and lines starting with `#` are skipped.
+pub fn settings(text: &str) -> Result<Vec<(&str, &str)>, ParseError> {
+    let mut settings = Vec::new();
+    for line in text.lines() {
+        if line.trim().is_empty() || line.trim_start().starts_with('#') {
+            continue;
+        }
+
+        let (key, value) = split_once(text, line, "=")?;
+        if key.trim().is_empty() {
+            return Err(ParseError::at(text, line, "expected a name before '='"));
+        }
+        settings.push((key.trim(), value.trim()));
+    }
+
+    Ok(settings)
+}
+
+/// The parts of `text` separated by blank lines, without their trailing
+/// line break.
+pub fn blocks(text: &str) -> impl Iterator<Item = &str> {
+    let blank = |line: &str| line.trim().is_empty();
+    let line_end =
+        |text: &str, from: usize| text[from..].find('\n').map_or(text.len(), |i| from + i + 1);
+
+    let mut rest = text;
+    iter::from_fn(move || {
+        while !rest.is_empty() && blank(&rest[..line_end(rest, 0)]) {
+            rest = &rest[line_end(rest, 0)..];
+        }
+        if rest.is_empty() {
+            return None;
+        }
+
+        let mut end = 0;
+        while end < rest.len() && !blank(&rest[end..line_end(rest, end)]) {
+            end = line_end(rest, end);
+        }
+
+        let block = rest[..end].trim_end_matches(['\n', '\r']);
+        rest = &rest[end..];
+        Some(block)
+    })
+}
+
+/// Something that takes up one line of input.
+pub trait Record: Sized {
+    /// Parses `line`, a slice of `input`.
+    fn parse(input: &str, line: &str) -> Result<Self, ParseError>;
+}
+
+/// A record per line of `text`, a slice of `input`. Blank lines are skipped.
+pub fn records<R: Record>(input: &str, text: &str) -> Result<Vec<R>, ParseError> {
+    text.lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| R::parse(input, line))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extracting_ints() {
+        let text = "Card 12: 41 -48 | x83";
+        assert_eq!(ints::<u32>(text, text), Ok(vec![12, 41, 48, 83]));
+        assert_eq!(signed_ints::<i32>(text, text), Ok(vec![12, 41, -48, 83]));
+        assert_eq!(
+            signed_ints::<i64>("1-2 --3", "1-2 --3"),
+            Ok(vec![1, -2, -3])
+        );
+
+        let text = "1\n2 300";
+        let err = ints::<u8>(text, text).unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 3, "300"));
+        assert_eq!(err.reason, "expected a number that fits in u8");
+    }
+
+    #[test]
+    fn separated_numbers() {
+        let text = "19, 13, 30 @ -2,  1, -2";
+        let (pos, vel) = split_once(text, text, "@").unwrap();
+        assert_eq!(array(text, pos, ","), Ok([19, 13, 30]));
+        assert_eq!(array(text, vel, ","), Ok([-2.0, 1.0, -2.0]));
+
+        let err = array::<i32, 2>(text, pos, ",").unwrap_err();
+        assert_eq!((err.column, err.text.as_str()), (1, "19, 13, 30 "));
+
+        let text = "7 x 9";
+        let err = numbers::<u32>(text, text).unwrap_err();
+        assert_eq!((err.column, err.reason.as_str()), (3, "expected a number"));
+    }
+
+    #[test]
+    fn keys_and_values() {
+        let text = "px{x=787,m=2655}";
+        let inner = delimited(text, &text[2..], "{", "}").unwrap();
+        assert_eq!(
+            key_values(text, inner, ",", "="),
+            Ok(vec![("x", 787), ("m", 2655)])
+        );
+
+        let err = delimited(text, text, "(", ")").unwrap_err();
+        assert_eq!(err.reason, "expected \"(...)\"");
+        let err = prefixed(text, text, "py").unwrap_err();
+        assert_eq!(err.reason, "expected \"py\"");
+    }
+
+    #[test]
+    fn settings_files() {
+        let text = "# comment\n cards = 234 \n\nwild=\n";
+        assert_eq!(settings(text), Ok(vec![("cards", "234"), ("wild", "")]));
+
+        let err = settings("a = 1\nb 2\n").unwrap_err();
+        assert_eq!((err.line, err.reason.as_str()), (2, "expected \"=\""));
+        let err = settings("a = 1\n = 2\n").unwrap_err();
+        assert_eq!(
+            (err.line, err.reason.as_str()),
+            (2, "expected a name before '='")
+        );
+    }
+
+    #[test]
+    fn splitting_blocks() {
+        let text = "\na\nb\n\n\nc\n  \nd\r\ne\r\n";
+        let blocks: Vec<&str> = blocks(text).collect();
+        assert_eq!(blocks, ["a\nb", "c", "d\r\ne"]);
+        assert_eq!(ParseError::at(text, blocks[1], "").line, 6);
+
+        assert_eq!(super::blocks("").count(), 0);
+        assert_eq!(super::blocks("x").collect::<Vec<_>>(), ["x"]);
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Move(char, u32);
+
+    impl Record for Move {
+        fn parse(input: &str, line: &str) -> Result<Move, ParseError> {
+            let (dir, steps) = split_once(input, line, " ")?;
+            match dir {
+                "L" | "R" => Ok(Move(dir.chars().next().unwrap(), number(input, steps)?)),
+                _ => Err(ParseError::at(input, dir, "expected L or R")),
+            }
+        }
+    }
+
+    #[test]
+    fn parsing_records() {
+        let text = "L 1\nR 20\n\n";
+        assert_eq!(records(text, text), Ok(vec![Move('L', 1), Move('R', 20)]));
+
+        let text = "L 1\nU 2\n";
+        let err = records::<Move>(text, text).unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 1, "U"));
+    }
+}
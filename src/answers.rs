@@ -0,0 +1,63 @@
+use common::Answer;
+use std::collections::HashMap;
+use std::fs;
+use toml::{Table, Value};
+
+/// Known good answers by day, `None` for parts without one.
+pub type Expected = HashMap<u8, [Option<Answer>; 2]>;
+
+pub fn default_path() -> String {
+    format!("{}/answers.toml", env!("CARGO_MANIFEST_DIR"))
+}
+
+fn to_answer(value: &Value) -> Option<Answer> {
+    match value {
+        Value::Integer(n) => Some(Answer::Int(*n as i128)),
+        Value::Array(pair) => match pair[..] {
+            [Value::Integer(a), Value::Integer(b)] => {
+                Some(Answer::Pair(a.try_into().ok()?, b.try_into().ok()?))
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Reads a file with a `[dayN]` table per day, each holding `part1` and
+/// `part2` keys.
+pub fn load(path: &str) -> Result<Expected, String> {
+    let text =
+        fs::read_to_string(path).map_err(|err| format!("failed to read {}: {}", path, err))?;
+    let table: Table = text.parse().map_err(|err| format!("{}: {}", path, err))?;
+
+    let mut expected = HashMap::new();
+    for (name, parts) in table.iter() {
+        let day = name
+            .strip_prefix("day")
+            .and_then(|n| n.parse::<u8>().ok())
+            .ok_or_else(|| format!("{}: expected a [dayN] table, found [{}]", path, name))?;
+        let parts = parts
+            .as_table()
+            .ok_or_else(|| format!("{}: {} is not a table", path, name))?;
+
+        let mut answers = [None, None];
+        for (key, value) in parts.iter() {
+            let part = match key.as_str() {
+                "part1" => 0,
+                "part2" => 1,
+                _ => return Err(format!("{}: unexpected key {}.{}", path, name, key)),
+            };
+            let answer = to_answer(value).ok_or_else(|| {
+                format!(
+                    "{}: {}.{} should be a number or a pair of numbers",
+                    path, name, key
+                )
+            })?;
+            answers[part] = Some(answer);
+        }
+
+        expected.insert(day, answers);
+    }
+
+    Ok(expected)
+}
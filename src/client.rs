@@ -0,0 +1,116 @@
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use std::{env, fs, thread};
+use ureq::Agent;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com/2023";
+const USER_AGENT: &str = "AdventOfCode2023 aoc runner";
+
+/// Talks to the puzzle server, logged in with a session cookie. Requests are
+/// spaced at least `min_interval` apart, also across runs: the time of the
+/// last one is kept in the `stamp` file.
+pub struct Client {
+    agent: Agent,
+    base_url: String,
+    session: String,
+    min_interval: Duration,
+    stamp: PathBuf,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str, min_interval: Duration, stamp: PathBuf) -> Client {
+        let agent = Agent::config_builder()
+            .timeout_global(Some(Duration::from_secs(30)))
+            .build()
+            .into();
+
+        Client {
+            agent,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            min_interval,
+            stamp,
+        }
+    }
+
+    /// A client configured from the `AOC_SESSION` and `AOC_BASE_URL`
+    /// environment variables, `base_url` overrides the latter.
+    pub fn from_env(base_url: Option<String>) -> Result<Client, String> {
+        let session = env::var("AOC_SESSION")
+            .map_err(|_| "set AOC_SESSION to the value of your session cookie")?;
+        let base_url = base_url
+            .or_else(|| env::var("AOC_BASE_URL").ok())
+            .unwrap_or_else(|| DEFAULT_BASE_URL.to_string());
+        let stamp = PathBuf::from(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/target/.aoc-last-request"
+        ));
+
+        Ok(Client::new(
+            &base_url,
+            &session,
+            Duration::from_secs(5),
+            stamp,
+        ))
+    }
+
+    // Sleeps until `min_interval` has passed since the last request.
+    fn wait_turn(&self) {
+        let now = || {
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+        };
+
+        let last = fs::read_to_string(&self.stamp)
+            .ok()
+            .and_then(|millis| millis.trim().parse().ok())
+            .map(Duration::from_millis);
+        if let Some(last) = last {
+            let since = now().saturating_sub(last);
+            if since < self.min_interval {
+                thread::sleep(self.min_interval - since);
+            }
+        }
+
+        if let Some(dir) = self.stamp.parent() {
+            let _ = fs::create_dir_all(dir);
+        }
+        let _ = fs::write(&self.stamp, now().as_millis().to_string());
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}/{}", self.base_url, path.trim_start_matches('/'))
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+
+    pub fn get(&self, path: &str) -> Result<String, String> {
+        self.wait_turn();
+
+        let url = self.url(path);
+        self.agent
+            .get(&url)
+            .header("Cookie", &self.cookie())
+            .header("User-Agent", USER_AGENT)
+            .call()
+            .and_then(|mut response| response.body_mut().read_to_string())
+            .map_err(|err| format!("GET {}: {}", url, err))
+    }
+
+    /// POSTs `fields` as a form.
+    pub fn post_form(&self, path: &str, fields: &[(&str, &str)]) -> Result<String, String> {
+        self.wait_turn();
+
+        let url = self.url(path);
+        self.agent
+            .post(&url)
+            .header("Cookie", &self.cookie())
+            .header("User-Agent", USER_AGENT)
+            .send_form(fields.iter().copied())
+            .and_then(|mut response| response.body_mut().read_to_string())
+            .map_err(|err| format!("POST {}: {}", url, err))
+    }
+}
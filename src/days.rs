@@ -0,0 +1,78 @@
+use common::bench::{self, Bench};
+use common::solution::{self, Answers, Solution};
+use common::ParseError;
+
+pub struct Day {
+    pub number: u8,
+    pub solve: fn(&str, Option<u8>) -> Result<Answers, ParseError>,
+    pub bench: fn(&str, Option<u8>, u32) -> Result<Bench, ParseError>,
+    /// See [`Solution::RUNNABLE`].
+    pub runnable: [bool; 2],
+}
+
+impl Day {
+    pub fn default_input(&self) -> String {
+        format!(
+            "{}/day{}/input.txt",
+            env!("CARGO_MANIFEST_DIR"),
+            self.number
+        )
+    }
+
+    /// Which parts to run when asked for `part`, or both if `None`.
+    pub fn parts_to_run(&self, part: Option<u8>) -> [bool; 2] {
+        [
+            part != Some(2) && self.runnable[0],
+            part != Some(1) && self.runnable[1],
+        ]
+    }
+
+    /// The parts asked for that can't run.
+    pub fn skipped(&self, part: Option<u8>) -> [bool; 2] {
+        let run = self.parts_to_run(part);
+        [part != Some(2) && !run[0], part != Some(1) && !run[1]]
+    }
+}
+
+macro_rules! day {
+    ($solution:ty) => {
+        Day {
+            number: <$solution>::DAY,
+            solve: solution::solve::<$solution>,
+            bench: bench::bench::<$solution>,
+            runnable: <$solution>::RUNNABLE,
+        }
+    };
+}
+
+pub const DAYS: [Day; 25] = [
+    day!(day1::Day1),
+    day!(day2::Day2),
+    day!(day3::Day3),
+    day!(day4::Day4),
+    day!(day5::Day5),
+    day!(day6::Day6),
+    day!(day7::Day7),
+    day!(day8::Day8),
+    day!(day9::Day9),
+    day!(day10::Day10),
+    day!(day11::Day11),
+    day!(day12::Day12),
+    day!(day13::Day13),
+    day!(day14::Day14),
+    day!(day15::Day15),
+    day!(day16::Day16),
+    day!(day17::Day17),
+    day!(day18::Day18),
+    day!(day19::Day19),
+    day!(day20::Day20),
+    day!(day21::Day21),
+    day!(day22::Day22),
+    day!(day23::Day23),
+    day!(day24::Day24),
+    day!(day25::Day25),
+];
+
+pub fn get(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.number == number)
+}
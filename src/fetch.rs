@@ -0,0 +1,98 @@
+use crate::client::Client;
+use std::fs;
+use std::path::Path;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Fetched {
+    Cached,
+    Downloaded,
+}
+
+/// Downloads the input of `day` to `dest`, unless it's already there.
+pub fn fetch(client: &Client, day: u8, dest: &Path) -> Result<Fetched, String> {
+    if dest.exists() {
+        return Ok(Fetched::Cached);
+    }
+
+    let input = client.get(&format!("day/{}/input", day))?;
+    if input.is_empty() {
+        return Err(format!("day {}: the server sent an empty input", day));
+    }
+
+    // Written next to it first, a half written input.txt would never be
+    // downloaded again.
+    let partial = dest.with_extension("part");
+    fs::write(&partial, &input)
+        .and_then(|_| fs::rename(&partial, dest))
+        .map_err(|err| format!("failed to write {}: {}", dest.display(), err))?;
+
+    Ok(Fetched::Downloaded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_server::MockServer;
+    use std::path::PathBuf;
+    use std::time::{Duration, Instant};
+    use std::{env, process};
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-fetch-{}-{}", name, process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn client(server: &MockServer, dir: &Path, min_interval: Duration) -> Client {
+        Client::new(&server.url, "abc123", min_interval, dir.join("stamp"))
+    }
+
+    #[test]
+    fn downloads_once_then_uses_the_cache() {
+        let server = MockServer::start(|req| match req.path.as_str() {
+            "/day/5/input" => (200, "seeds: 1 2\n".to_string()),
+            _ => (404, "not found".to_string()),
+        });
+        let dir = temp_dir("cache");
+        let client = client(&server, &dir, Duration::ZERO);
+        let dest = dir.join("input.txt");
+
+        assert_eq!(fetch(&client, 5, &dest), Ok(Fetched::Downloaded));
+        assert_eq!(fs::read_to_string(&dest).unwrap(), "seeds: 1 2\n");
+        assert_eq!(fetch(&client, 5, &dest), Ok(Fetched::Cached));
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "GET");
+        assert_eq!(requests[0].header("cookie"), Some("session=abc123"));
+    }
+
+    #[test]
+    fn errors_leave_nothing_behind() {
+        let server = MockServer::start(|_| (400, "Please log in".to_string()));
+        let dir = temp_dir("error");
+        let client = client(&server, &dir, Duration::ZERO);
+        let dest = dir.join("input.txt");
+
+        let err = fetch(&client, 1, &dest).unwrap_err();
+        assert!(err.contains("400"), "{}", err);
+        assert!(!dest.exists());
+        assert!(!dest.with_extension("part").exists());
+    }
+
+    #[test]
+    fn requests_are_spaced_out() {
+        let server = MockServer::start(|_| (200, "input\n".to_string()));
+        let dir = temp_dir("rate");
+        let client = client(&server, &dir, Duration::from_millis(300));
+
+        let start = Instant::now();
+        fetch(&client, 1, &dir.join("1.txt")).unwrap();
+        fetch(&client, 2, &dir.join("2.txt")).unwrap();
+        fetch(&client, 3, &dir.join("3.txt")).unwrap();
+
+        assert!(start.elapsed() >= Duration::from_millis(600));
+        assert_eq!(server.requests().len(), 3);
+    }
+}
@@ -1,3 +1,506 @@
+mod answers;
+mod client;
+mod days;
+mod fetch;
+#[cfg(test)]
+mod mock_server;
+mod submit;
+
+use client::Client;
+use common::bench::Timings;
+use common::input::{self, Source};
+use common::report::{self, Format};
+use common::Answer;
+use days::Day;
+use fetch::Fetched;
+use std::path::Path;
+use std::time::Duration;
+use std::{env, process};
+use submit::{History, Verdict};
+
+const USAGE: &str = "\
+usage: aoc run <day> [--part 1|2] [--input <file>] [--bench [--runs <n>]] [--format text|json]
+       aoc run --all [--part 1|2] [--bench [--runs <n>]] [--format text|json]
+       aoc check [<day>] [--answers <file>]
+       aoc fetch <day>|--all [--base-url <url>]
+       aoc submit <day> <part> <answer> [--base-url <url>]
+
+fetch and submit read the session cookie from AOC_SESSION and the base URL
+from AOC_BASE_URL, which defaults to https://adventofcode.com/2023. Submitted
+answers are logged in submissions.tsv.";
+
+const DEFAULT_RUNS: u32 = 10;
+
+struct RunArgs {
+    days: Vec<&'static Day>,
+    part: Option<u8>,
+    input: Option<Source>,
+    /// How many times to run each step when benchmarking.
+    bench: Option<u32>,
+    format: Format,
+}
+
+fn parse_day(arg: &str) -> Result<&'static Day, String> {
+    arg.parse::<u8>()
+        .ok()
+        .and_then(days::get)
+        .ok_or(format!("invalid day: {}", arg))
+}
+
+fn parse_part(arg: &str) -> Result<u8, String> {
+    match arg {
+        "1" => Ok(1),
+        "2" => Ok(2),
+        _ => Err(format!("invalid part: {}", arg)),
+    }
+}
+
+fn parse_run_args(args: &[String]) -> Result<RunArgs, String> {
+    let mut day = None;
+    let mut all = false;
+    let mut part = None;
+    let mut input = None;
+    let mut bench = false;
+    let mut runs = None;
+    let mut format = Format::Text;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--all" => all = true,
+            "--part" => {
+                let val = args.next().ok_or("--part needs a value")?;
+                part = Some(parse_part(val)?);
+            }
+            "--input" => {
+                let val = args.next().ok_or("--input needs a value")?;
+                input = Some(Source::from_arg(val));
+            }
+            "--bench" => bench = true,
+            "--runs" => {
+                let val = args.next().ok_or("--runs needs a value")?;
+                runs = match val.parse::<u32>() {
+                    Ok(n) if n > 0 => Some(n),
+                    _ => return Err(format!("invalid number of runs: {}", val)),
+                };
+            }
+            "--format" => {
+                let val = args.next().ok_or("--format needs a value")?;
+                format = Format::from_arg(val).ok_or(format!("invalid format: {}", val))?;
+            }
+            _ if day.is_none() && !arg.starts_with("--") => day = Some(parse_day(arg)?),
+            _ => return Err(format!("unexpected argument: {}", arg)),
+        }
+    }
+
+    let days = match (day, all) {
+        (Some(day), false) => vec![day],
+        (None, true) if input.is_some() => return Err("--input can't be used with --all".into()),
+        (None, true) => days::DAYS.iter().collect(),
+        (Some(_), true) => return Err("pass either a day or --all".into()),
+        (None, false) => return Err("missing day".into()),
+    };
+
+    let bench = match (bench, runs) {
+        (true, runs) => Some(runs.unwrap_or(DEFAULT_RUNS)),
+        (false, None) => None,
+        (false, Some(_)) => return Err("--runs can only be used with --bench".into()),
+    };
+
+    Ok(RunArgs {
+        days,
+        part,
+        input,
+        bench,
+        format,
+    })
+}
+
+fn print_row(day: &str, part1: &str, part2: &str) {
+    println!("{:>3}  {:<20}  {}", day, part1, part2);
+}
+
+// Reads the input for `day`, printing the error if that fails.
+fn read_input(day: &Day, from: &Option<Source>) -> Option<String> {
+    let from = match from {
+        Some(from) => from.clone(),
+        None => Source::File(day.default_input().into()),
+    };
+
+    input::read(&from)
+        .map_err(|err| eprintln!("day {}: {}", day.number, err))
+        .ok()
+}
+
+// The part to pass to `solve` or `bench` so only `parts` run, `None` if
+// neither does.
+fn part_arg(parts: [bool; 2]) -> Option<Option<u8>> {
+    match parts {
+        [true, true] => Some(None),
+        [true, false] => Some(Some(1)),
+        [false, true] => Some(Some(2)),
+        [false, false] => None,
+    }
+}
+
+// Returns false if any day's input couldn't be read or parsed.
+fn run(args: RunArgs) -> bool {
+    let mut ok = true;
+
+    if args.format == Format::Text {
+        print_row("Day", "Part 1", "Part 2");
+    }
+    for day in args.days {
+        let Some(input) = read_input(day, &args.input) else {
+            ok = false;
+            continue;
+        };
+
+        let answers = match part_arg(day.parts_to_run(args.part)) {
+            Some(part) => match (day.solve)(&input, part) {
+                Ok(answers) => answers,
+                Err(err) => {
+                    eprintln!("{}", err);
+                    ok = false;
+                    continue;
+                }
+            },
+            None => [None, None],
+        };
+
+        let skipped = day.skipped(args.part);
+        match args.format {
+            Format::Text => {
+                let [part1, part2] = [0, 1].map(|i| match (&answers[i], skipped[i]) {
+                    (Some(timed), _) => timed.answer.to_string(),
+                    (None, true) => "skipped".to_string(),
+                    (None, false) => Answer::None.to_string(),
+                });
+                print_row(&day.number.to_string(), &part1, &part2);
+            }
+            Format::Json => {
+                for (part, (timed, skipped)) in (1..).zip(answers.into_iter().zip(skipped)) {
+                    if let Some(timed) = timed {
+                        println!("{}", report::answer_json(day.number, part, &timed));
+                    } else if skipped {
+                        println!("{}", report::skipped_json(day.number, part));
+                    }
+                }
+            }
+        }
+    }
+
+    ok
+}
+
+fn print_timings_row(day: &str, step: &str, min: &str, mean: &str, max: &str) {
+    println!(
+        "{:>3}  {:<5}  {:>12}  {:>12}  {:>12}",
+        day, step, min, mean, max
+    );
+}
+
+fn print_timings(day: u8, step: &str, runs: u32, timings: Timings, format: Format) {
+    let ms = |d: Duration| format!("{:.3} ms", d.as_secs_f64() * 1000.0);
+    match format {
+        Format::Text => print_timings_row(
+            &day.to_string(),
+            step,
+            &ms(timings.min),
+            &ms(timings.mean),
+            &ms(timings.max),
+        ),
+        Format::Json => println!("{}", report::timings_json(day, step, runs, &timings)),
+    }
+}
+
+// Like `run`, but times parsing and each part instead of printing answers.
+fn bench(args: RunArgs, runs: u32) -> bool {
+    let mut ok = true;
+
+    if args.format == Format::Text {
+        print_timings_row("Day", "Step", "Min", "Mean", "Max");
+    }
+    for day in args.days {
+        let Some(input) = read_input(day, &args.input) else {
+            ok = false;
+            continue;
+        };
+
+        let timings = match part_arg(day.parts_to_run(args.part)) {
+            Some(part) => match (day.bench)(&input, part, runs) {
+                Ok(bench) => {
+                    print_timings(day.number, "parse", runs, bench.parse, args.format);
+                    bench.parts
+                }
+                Err(err) => {
+                    eprintln!("{}", err);
+                    ok = false;
+                    continue;
+                }
+            },
+            None => [None, None],
+        };
+
+        let skipped = day.skipped(args.part);
+        for (part, (timings, skipped)) in (1..).zip(timings.into_iter().zip(skipped)) {
+            let step = format!("part{}", part);
+            match (timings, skipped) {
+                (Some(timings), _) => print_timings(day.number, &step, runs, timings, args.format),
+                (None, true) => match args.format {
+                    Format::Text => {
+                        print_timings_row(&day.number.to_string(), &step, "skipped", "", "")
+                    }
+                    Format::Json => println!("{}", report::skipped_json(day.number, part)),
+                },
+                (None, false) => {}
+            }
+        }
+    }
+
+    ok
+}
+
+struct CheckArgs {
+    days: Vec<&'static Day>,
+    answers: String,
+}
+
+fn parse_check_args(args: &[String]) -> Result<CheckArgs, String> {
+    let mut day = None;
+    let mut answers = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--answers" => {
+                let val = args.next().ok_or("--answers needs a value")?;
+                answers = Some(val.clone());
+            }
+            _ if day.is_none() && !arg.starts_with("--") => day = Some(parse_day(arg)?),
+            _ => return Err(format!("unexpected argument: {}", arg)),
+        }
+    }
+
+    Ok(CheckArgs {
+        days: day.map_or_else(|| days::DAYS.iter().collect(), |day| vec![day]),
+        answers: answers.unwrap_or_else(answers::default_path),
+    })
+}
+
+// Runs every part that has a known answer and compares it. Returns false on
+// any mismatch, or if an input couldn't be read or parsed.
+fn check(args: CheckArgs) -> bool {
+    let expected = match answers::load(&args.answers) {
+        Ok(expected) => expected,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            return false;
+        }
+    };
+
+    let mut ok = true;
+    for day in args.days {
+        let known = expected.get(&day.number).cloned().unwrap_or_default();
+        let wanted = [0, 1].map(|i| known[i].is_some() && day.runnable[i]);
+        let Some(part) = part_arg(wanted) else {
+            for (part, known) in (1..).zip(known.iter()) {
+                match known {
+                    Some(_) => println!("{:>3}  part{}  skipped", day.number, part),
+                    None => println!("{:>3}  part{}  unknown", day.number, part),
+                }
+            }
+            continue;
+        };
+
+        let Some(input) = read_input(day, &None) else {
+            ok = false;
+            continue;
+        };
+        let answers = match (day.solve)(&input, part) {
+            Ok(answers) => answers,
+            Err(err) => {
+                eprintln!("{}", err);
+                ok = false;
+                continue;
+            }
+        };
+
+        for (part, (known, timed)) in (1..).zip(known.into_iter().zip(answers)) {
+            match (known, timed) {
+                (Some(known), Some(timed)) if known == timed.answer => {
+                    println!("{:>3}  part{}  pass     {}", day.number, part, timed.answer)
+                }
+                (Some(known), Some(timed)) => {
+                    println!(
+                        "{:>3}  part{}  FAIL     got {}, expected {}",
+                        day.number, part, timed.answer, known
+                    );
+                    ok = false;
+                }
+                (Some(_), None) => println!("{:>3}  part{}  skipped", day.number, part),
+                _ => println!("{:>3}  part{}  unknown", day.number, part),
+            }
+        }
+    }
+
+    ok
+}
+
+struct FetchArgs {
+    days: Vec<&'static Day>,
+    base_url: Option<String>,
+}
+
+fn parse_fetch_args(args: &[String]) -> Result<FetchArgs, String> {
+    let mut day = None;
+    let mut all = false;
+    let mut base_url = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--all" => all = true,
+            "--base-url" => {
+                let val = args.next().ok_or("--base-url needs a value")?;
+                base_url = Some(val.clone());
+            }
+            _ if day.is_none() && !arg.starts_with("--") => day = Some(parse_day(arg)?),
+            _ => return Err(format!("unexpected argument: {}", arg)),
+        }
+    }
+
+    let days = match (day, all) {
+        (Some(day), false) => vec![day],
+        (None, true) => days::DAYS.iter().collect(),
+        (Some(_), true) => return Err("pass either a day or --all".into()),
+        (None, false) => return Err("missing day".into()),
+    };
+
+    Ok(FetchArgs { days, base_url })
+}
+
+// Returns false if any input couldn't be downloaded.
+fn fetch_inputs(args: FetchArgs) -> bool {
+    let client = match Client::from_env(args.base_url) {
+        Ok(client) => client,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            return false;
+        }
+    };
+
+    let mut ok = true;
+    for day in args.days {
+        let dest = day.default_input();
+        match fetch::fetch(&client, day.number, Path::new(&dest)) {
+            Ok(Fetched::Cached) => println!("day {}: already have {}", day.number, dest),
+            Ok(Fetched::Downloaded) => println!("day {}: saved {}", day.number, dest),
+            Err(err) => {
+                eprintln!("day {}: {}", day.number, err);
+                ok = false;
+            }
+        }
+    }
+
+    ok
+}
+
+struct SubmitArgs {
+    day: &'static Day,
+    part: u8,
+    answer: String,
+    base_url: Option<String>,
+}
+
+fn parse_submit_args(args: &[String]) -> Result<SubmitArgs, String> {
+    let mut positional = Vec::new();
+    let mut base_url = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--base-url" => {
+                let val = args.next().ok_or("--base-url needs a value")?;
+                base_url = Some(val.clone());
+            }
+            _ if positional.len() < 3 && !arg.starts_with("--") => positional.push(arg),
+            _ => return Err(format!("unexpected argument: {}", arg)),
+        }
+    }
+
+    let [day, part, answer] = positional[..] else {
+        return Err("expected a day, a part and an answer".into());
+    };
+    Ok(SubmitArgs {
+        day: parse_day(day)?,
+        part: parse_part(part)?,
+        answer: answer.clone(),
+        base_url,
+    })
+}
+
+// Returns false unless the answer was accepted.
+fn submit_answer(args: SubmitArgs) -> bool {
+    let result = Client::from_env(args.base_url).and_then(|client| {
+        let history = History::new(History::default_path());
+        submit::submit(&client, &history, args.day.number, args.part, &args.answer)
+    });
+
+    match result {
+        Ok(verdict) => {
+            println!("day {} part {}: {}", args.day.number, args.part, verdict);
+            verdict == Verdict::Correct
+        }
+        Err(err) => {
+            eprintln!("error: {}", err);
+            false
+        }
+    }
+}
+
 fn main() {
-    println!("Hello, world!");
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let ok = match args.first().map(String::as_str) {
+        Some("run") => match parse_run_args(&args[1..]) {
+            Ok(run_args) => match run_args.bench {
+                Some(runs) => bench(run_args, runs),
+                None => run(run_args),
+            },
+            Err(err) => {
+                eprintln!("error: {}\n{}", err, USAGE);
+                process::exit(2);
+            }
+        },
+        Some("check") => match parse_check_args(&args[1..]) {
+            Ok(check_args) => check(check_args),
+            Err(err) => {
+                eprintln!("error: {}\n{}", err, USAGE);
+                process::exit(2);
+            }
+        },
+        Some("fetch") => match parse_fetch_args(&args[1..]) {
+            Ok(fetch_args) => fetch_inputs(fetch_args),
+            Err(err) => {
+                eprintln!("error: {}\n{}", err, USAGE);
+                process::exit(2);
+            }
+        },
+        Some("submit") => match parse_submit_args(&args[1..]) {
+            Ok(submit_args) => submit_answer(submit_args),
+            Err(err) => {
+                eprintln!("error: {}\n{}", err, USAGE);
+                process::exit(2);
+            }
+        },
+        _ => {
+            eprintln!("{}", USAGE);
+            process::exit(2);
+        }
+    };
+
+    if !ok {
+        process::exit(1);
+    }
 }
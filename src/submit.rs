@@ -0,0 +1,370 @@
+use crate::client::Client;
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::{ErrorKind, Write};
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// What the server made of a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without saying which way.
+    Wrong,
+    /// Submitted too soon after the previous answer, with the seconds left.
+    Wait(u64),
+    /// The part is already solved, or its first part isn't yet.
+    WrongLevel,
+    /// A response none of the above matched.
+    Unknown,
+}
+
+impl Verdict {
+    /// Reads the verdict from the page the server answers a submission with.
+    pub fn from_response(page: &str) -> Verdict {
+        if page.contains("That's the right answer") {
+            Verdict::Correct
+        } else if page.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if page.contains("your answer is too low") {
+            Verdict::TooLow
+        } else if page.contains("That's not the right answer") {
+            Verdict::Wrong
+        } else if let Some(secs) = wait_time(page) {
+            Verdict::Wait(secs)
+        } else if page.contains("You don't seem to be solving the right level") {
+            Verdict::WrongLevel
+        } else {
+            Verdict::Unknown
+        }
+    }
+
+    pub fn is_rejection(self) -> bool {
+        matches!(self, Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong)
+    }
+
+    fn to_field(self) -> String {
+        match self {
+            Verdict::Correct => "correct".to_string(),
+            Verdict::TooHigh => "too-high".to_string(),
+            Verdict::TooLow => "too-low".to_string(),
+            Verdict::Wrong => "wrong".to_string(),
+            Verdict::Wait(secs) => format!("wait-{}", secs),
+            Verdict::WrongLevel => "wrong-level".to_string(),
+            Verdict::Unknown => "unknown".to_string(),
+        }
+    }
+
+    fn from_field(field: &str) -> Option<Verdict> {
+        Some(match field {
+            "correct" => Verdict::Correct,
+            "too-high" => Verdict::TooHigh,
+            "too-low" => Verdict::TooLow,
+            "wrong" => Verdict::Wrong,
+            "wrong-level" => Verdict::WrongLevel,
+            "unknown" => Verdict::Unknown,
+            _ => Verdict::Wait(field.strip_prefix("wait-")?.parse().ok()?),
+        })
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "wrong, too high"),
+            Verdict::TooLow => write!(f, "wrong, too low"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::Wait(secs) => write!(f, "too soon, wait {}s", secs),
+            Verdict::WrongLevel => write!(f, "not the right level, already solved?"),
+            Verdict::Unknown => write!(f, "unrecognised response"),
+        }
+    }
+}
+
+// The time in "You have 1m 5s left to wait".
+fn wait_time(page: &str) -> Option<u64> {
+    let (before, _) = page.split_once(" left to wait")?;
+    let (_, amount) = before.rsplit_once("You have ")?;
+
+    amount
+        .split_whitespace()
+        .map(|word| {
+            let unit = match word.chars().last()? {
+                'h' => 3600,
+                'm' => 60,
+                's' => 1,
+                _ => return None,
+            };
+            Some(word[..word.len() - 1].parse::<u64>().ok()? * unit)
+        })
+        .sum()
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attempt {
+    /// Seconds since the Unix epoch.
+    pub time: u64,
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+/// Every answer submitted so far, one tab separated line each.
+pub struct History {
+    path: PathBuf,
+}
+
+impl History {
+    pub fn new(path: PathBuf) -> History {
+        History { path }
+    }
+
+    pub fn default_path() -> PathBuf {
+        PathBuf::from(concat!(env!("CARGO_MANIFEST_DIR"), "/submissions.tsv"))
+    }
+
+    pub fn attempts(&self) -> Result<Vec<Attempt>, String> {
+        let text = match fs::read_to_string(&self.path) {
+            Ok(text) => text,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(err) => return Err(format!("failed to read {}: {}", self.path.display(), err)),
+        };
+
+        (1..)
+            .zip(text.lines())
+            .map(|(n, line)| {
+                parse_attempt(line)
+                    .ok_or_else(|| format!("{}:{}: malformed entry", self.path.display(), n))
+            })
+            .collect()
+    }
+
+    fn record(&self, attempt: &Attempt) -> Result<(), String> {
+        let line = format!(
+            "{}\t{}\t{}\t{}\t{}\n",
+            attempt.time,
+            attempt.day,
+            attempt.part,
+            attempt.verdict.to_field(),
+            attempt.answer
+        );
+
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .and_then(|mut file| file.write_all(line.as_bytes()))
+            .map_err(|err| format!("failed to write {}: {}", self.path.display(), err))
+    }
+}
+
+fn parse_attempt(line: &str) -> Option<Attempt> {
+    let mut fields = line.split('\t');
+    let attempt = Attempt {
+        time: fields.next()?.parse().ok()?,
+        day: fields.next()?.parse().ok()?,
+        part: fields.next()?.parse().ok()?,
+        verdict: Verdict::from_field(fields.next()?)?,
+        answer: fields.next()?.to_string(),
+    };
+
+    match fields.next() {
+        Some(_) => None,
+        None => Some(attempt),
+    }
+}
+
+/// Submits `answer` for a part of `day` and records the verdict in
+/// `history`. Answers that were already rejected, parts that are already
+/// solved, and submissions while the server still wants us to wait are
+/// refused without asking the server.
+pub fn submit(
+    client: &Client,
+    history: &History,
+    day: u8,
+    part: u8,
+    answer: &str,
+) -> Result<Verdict, String> {
+    if answer.is_empty() || answer.contains(char::is_whitespace) {
+        return Err(format!("invalid answer: {:?}", answer));
+    }
+
+    let attempts = history.attempts()?;
+    let same_part = || attempts.iter().filter(|a| a.day == day && a.part == part);
+    if let Some(solved) = same_part().find(|a| a.verdict == Verdict::Correct) {
+        return Err(format!(
+            "day {} part {} is already solved, the answer was {}",
+            day, part, solved.answer
+        ));
+    }
+    if let Some(rejected) = same_part().find(|a| a.answer == answer && a.verdict.is_rejection()) {
+        return Err(format!(
+            "{} was already rejected for day {} part {} ({})",
+            answer, day, part, rejected.verdict
+        ));
+    }
+
+    let now = now();
+    let until = attempts
+        .iter()
+        .filter_map(|a| match a.verdict {
+            Verdict::Wait(secs) => Some(a.time + secs),
+            _ => None,
+        })
+        .max();
+    if let Some(until) = until.filter(|&until| until > now) {
+        return Err(format!(
+            "the server asked to wait, try again in {}s",
+            until - now
+        ));
+    }
+
+    let level = part.to_string();
+    let page = client.post_form(
+        &format!("day/{}/answer", day),
+        &[("level", &level), ("answer", answer)],
+    )?;
+
+    let verdict = Verdict::from_response(&page);
+    history.record(&Attempt {
+        time: now,
+        day,
+        part,
+        answer: answer.to_string(),
+        verdict,
+    })?;
+
+    Ok(verdict)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_server::MockServer;
+    use std::time::Duration;
+    use std::{env, process};
+
+    fn history(name: &str) -> History {
+        let path = env::temp_dir().join(format!("aoc-submit-{}-{}.tsv", name, process::id()));
+        let _ = fs::remove_file(&path);
+        History::new(path)
+    }
+
+    fn client(server: &MockServer) -> Client {
+        let stamp = env::temp_dir().join(format!("aoc-submit-stamp-{}", process::id()));
+        Client::new(&server.url, "abc123", Duration::ZERO, stamp)
+    }
+
+    fn page(text: &str) -> String {
+        format!(
+            "<html><body><main><article><p>{}</p></article></main></body></html>",
+            text
+        )
+    }
+
+    #[test]
+    fn verdicts() {
+        let cases = [
+            ("That's the right answer!  You are one gold star closer.", Verdict::Correct),
+            ("That's not the right answer; your answer is too high.", Verdict::TooHigh),
+            ("That's not the right answer; your answer is too low.", Verdict::TooLow),
+            ("That's not the right answer.  If you're stuck, ...", Verdict::Wrong),
+            (
+                "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 37s left to wait.",
+                Verdict::Wait(37),
+            ),
+            ("You have 1m 5s left to wait.", Verdict::Wait(65)),
+            ("You don't seem to be solving the right level.  Did you already complete it?", Verdict::WrongLevel),
+            ("Please log in", Verdict::Unknown),
+        ];
+        for (text, verdict) in cases {
+            assert_eq!(Verdict::from_response(&page(text)), verdict, "{}", text);
+            assert_eq!(Verdict::from_field(&verdict.to_field()), Some(verdict));
+        }
+    }
+
+    #[test]
+    fn posts_the_answer_and_records_it() {
+        let server = MockServer::start(|_| (200, page("That's the right answer!")));
+        let history = history("correct");
+
+        assert_eq!(
+            submit(&client(&server), &history, 3, 2, "4361"),
+            Ok(Verdict::Correct)
+        );
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].path, "/day/3/answer");
+        assert_eq!(requests[0].body, "level=2&answer=4361");
+        assert_eq!(requests[0].header("cookie"), Some("session=abc123"));
+
+        let attempts = history.attempts().unwrap();
+        assert_eq!(attempts.len(), 1);
+        assert_eq!((attempts[0].day, attempts[0].part), (3, 2));
+        assert_eq!(attempts[0].answer, "4361");
+        assert_eq!(attempts[0].verdict, Verdict::Correct);
+    }
+
+    #[test]
+    fn rejected_answers_are_not_resubmitted() {
+        let server = MockServer::start(|req| match req.body.as_str() {
+            "level=1&answer=100" => (
+                200,
+                page("That's not the right answer; your answer is too high."),
+            ),
+            _ => (200, page("That's the right answer!")),
+        });
+        let client = client(&server);
+        let history = history("rejected");
+
+        assert_eq!(submit(&client, &history, 1, 1, "100"), Ok(Verdict::TooHigh));
+        let err = submit(&client, &history, 1, 1, "100").unwrap_err();
+        assert!(err.contains("already rejected"), "{}", err);
+        assert_eq!(server.requests().len(), 1);
+
+        // The same answer is fine for the other part.
+        assert_eq!(submit(&client, &history, 1, 2, "100"), Ok(Verdict::Correct));
+        assert_eq!(submit(&client, &history, 1, 1, "42"), Ok(Verdict::Correct));
+        let err = submit(&client, &history, 1, 1, "43").unwrap_err();
+        assert!(err.contains("already solved"), "{}", err);
+        assert_eq!(server.requests().len(), 3);
+    }
+
+    #[test]
+    fn waits_when_told_to() {
+        let server = MockServer::start(|_| (200, page("You have 2m 0s left to wait.")));
+        let client = client(&server);
+        let history = history("wait");
+
+        assert_eq!(
+            submit(&client, &history, 7, 1, "6440"),
+            Ok(Verdict::Wait(120))
+        );
+        let err = submit(&client, &history, 7, 1, "6441").unwrap_err();
+        assert!(err.contains("wait"), "{}", err);
+        assert_eq!(server.requests().len(), 1);
+    }
+
+    #[test]
+    fn server_errors_are_not_recorded() {
+        let server = MockServer::start(|_| (500, "oops".to_string()));
+        let history = history("error");
+
+        let err = submit(&client(&server), &history, 2, 1, "8").unwrap_err();
+        assert!(err.contains("500"), "{}", err);
+        assert_eq!(history.attempts(), Ok(Vec::new()));
+    }
+}